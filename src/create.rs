@@ -1,27 +1,37 @@
-use crate::utils::TitleCase;
-use crate::Properties;
-use std::fs::OpenOptions;
-use std::io::{Seek, SeekFrom, Write};
-use std::{
-    fs::{create_dir, create_dir_all, File},
-    io::Error,
+use crate::models::{Field, Property};
+use crate::naming::{
+    camel_case, identifier, kebab_case, lower_words, pascal_case, sentence_case, snake_case,
+    url_path,
+};
+use crate::plan::Plan;
+use crate::project::{relative_import, ModelFile, Project, ServiceFile};
+use crate::set_properties::properties_table;
+use crate::templates::Templates;
+use crate::ts_patch::{array_references, insert_array_entry, upsert_enum_members};
+use crate::validate::{
+    validate_enum_values, validate_modifiers, validate_name, validate_property, validate_route,
 };
+use crate::Properties;
+use console::Style;
+use minijinja::value::Serde;
+use minijinja::{context, Value};
+use serde::{Deserialize, Serialize};
+use std::fs::read_to_string;
+use std::io::{Error, ErrorKind};
+use std::path::Path;
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct Config {
     section: String,
     section_plural: String,
     api_route: String,
     front_route: String,
     icon: String,
+    #[serde(default)]
     properties: Properties,
-}
-impl TitleCase for &str {
-    fn title(&self) -> String {
-        if !self.is_ascii() || self.is_empty() {
-            return String::new();
-        }
-        let (head, tail) = self.split_at(1);
-        head.to_uppercase() + tail
-    }
+    /// Also generate `nuevo`, `:id` and `:id/editar` pages next to the dialogs.
+    #[serde(default)]
+    pages: bool,
 }
 impl Config {
     pub fn new(
@@ -31,6 +41,7 @@ impl Config {
         front_route: String,
         icon: String,
         properties: Properties,
+        pages: bool,
     ) -> Config {
         Config {
             section,
@@ -39,999 +50,620 @@ impl Config {
             front_route,
             icon,
             properties,
+            pages,
         }
     }
-}
-pub fn create(config: Config) -> Result<(), Error> {
-    let base_path = format!("./src/app/views/{}", &config.api_route);
-    let service_path = format!("./src/app/services/http/{}", &config.api_route);
-    let model_name = &config.section.as_str().title();
-    let plural_titled = &config.section_plural.as_str().title();
-    create_dir_all(&base_path)?;
-    create_dir_all(&service_path)?;
-    // let templates_path = format!("{}/templates", &base_path);
-    // create_dir(&templates_path)?;
-    let form_path = format!("{}/pages/form", &base_path);
-    create_dir_all(&form_path)?;
-    let list_path = format!("{}/pages/list", &base_path);
-    create_dir(&list_path)?;
-    // let modal_path = format!("{}/pages/modal", &base_path);
-    // create_dir(&modal_path)?;
-    let show_path = format!("{}/pages/show", &base_path);
-    create_dir(&show_path)?;
-    let mut form_html = File::create(format!("{}/form.component.html", &form_path)).unwrap();
-    write_form_html(&mut form_html, &config.section)?;
-    let mut form_ts = File::create(format!("{}/form.component.ts", &form_path)).unwrap();
-    write_form_ts(&mut form_ts, &config.section, model_name)?;
 
-    let mut list_html = File::create(format!("{}/list.component.html", &list_path)).unwrap();
-    write_list_html(&mut list_html, &config.section_plural)?;
-    let mut list_ts = File::create(format!("{}/list.component.ts", &list_path)).unwrap();
-    write_list_ts(
-        &mut list_ts,
-        model_name,
-        &config.section,
-        plural_titled,
-        &config.section_plural,
-        &config.api_route,
-    )?;
+    /// Turns the pages on when asked from the command line, a spec can not turn them off.
+    pub fn add_pages(&mut self, pages: bool) {
+        self.pages |= pages;
+    }
 
-    let mut show_html = File::create(format!("{}/show.component.html", &show_path)).unwrap();
-    write_show_html(&mut show_html)?;
-    let mut show_ts = File::create(format!("{}/show.component.ts", &show_path)).unwrap();
-    write_show_ts(&mut show_ts, model_name, &config.section)?;
+    /// Every value of the config and the names derived from it, one per line.
+    pub fn summary(&self, key_color: &Style) -> String {
+        let rows = [
+            ("Section", self.section.clone()),
+            ("Section plural", self.section_plural.clone()),
+            ("Model", pascal_case(&self.section)),
+            ("Front route", url_path(&self.front_route)),
+            ("API route", url_path(&self.api_route)),
+            ("Icon", self.icon.clone()),
+            (
+                "Pages",
+                if self.pages {
+                    "dialogs and pages"
+                } else {
+                    "dialogs"
+                }
+                .to_owned(),
+            ),
+        ];
+        let mut summary: String = rows
+            .iter()
+            .map(|(label, value)| {
+                format!(
+                    "{} {}\n",
+                    key_color.apply_to(format!("{:16}", label)),
+                    value
+                )
+            })
+            .collect();
+        summary.push_str(&format!("{}\n", key_color.apply_to("Properties")));
+        if self.properties.is_empty() {
+            summary.push_str("  none, the model only has name\n");
+        } else {
+            summary.push_str(&properties_table(&self.properties));
+        }
+        summary
+    }
 
-    // let mut modal_html = File::create(format!("{}/modal.component.html", &modal_path)).unwrap();
-    // write_modal_html(&mut modal_html, &config.section)?;
-    // let mut modal_ts = File::create(format!("{}/modal.component.ts", &modal_path)).unwrap();
-    // write_modal_ts(&mut modal_ts, model_name, &config.section)?;
+    /// Applies the wizard and CLI checks to a config read from a spec file.
+    pub fn validate(&self) -> Result<(), String> {
+        let field = |name: &str, result: Result<String, String>| {
+            result.map_err(|err| format!("{}: {}", name, err))
+        };
+        field("section", validate_name(&self.section))?;
+        field("section_plural", validate_name(&self.section_plural))?;
+        field("front_route", validate_route(&self.front_route))?;
+        field("api_route", validate_route(&self.api_route))?;
+        let mut keys = Vec::new();
+        let mut controls = Vec::new();
+        for (key, property) in &self.properties {
+            let name = format!("properties.{}", key);
+            let valid = field(&name, validate_property(key))?;
+            if keys.contains(&valid) {
+                return Err(format!("{}: {} is declared more than once", name, valid));
+            }
+            let control = control_name(&identifier(&valid), property);
+            if controls.contains(&control) {
+                return Err(format!(
+                    "{}: {} is already the form field of another property",
+                    name, control
+                ));
+            }
+            keys.push(valid);
+            controls.push(control);
+            match property.kind {
+                Property::Enum => {
+                    field(
+                        &name,
+                        validate_enum_values(&property.values.join(",")).map(|_| String::new()),
+                    )?;
+                }
+                _ if !property.values.is_empty() => {
+                    return Err(format!("{}: values only apply to Enum properties", name));
+                }
+                _ => {}
+            }
+            validate_modifiers(property).map_err(|err| format!("{}: {}", name, err))?;
+        }
+        Ok(())
+    }
+}
+/// Builds the plan of every file to create or patch, nothing is written until it is applied.
+pub fn create(config: Config, project: &Project) -> Result<Plan, Error> {
+    let mut plan = Plan::default();
+    let templates = Templates::new(project.templates());
+    let model_name = pascal_case(&config.section);
+    let section_file = kebab_case(&config.section);
+    let api_folder = kebab_case(&config.api_route);
+    let base_path = project.path(&project.views).join(&api_folder);
+    let service_path = project.path(&project.services).join(&api_folder);
+    let context = template_context(&config, project, &project.models(), &project.services());
+    // let templates_path = base_path.join("templates");
+    let form_path = base_path.join("pages/form");
+    let list_path = base_path.join("pages/list");
+    // let modal_path = base_path.join("pages/modal");
+    let show_path = base_path.join("pages/show");
+    let files = [
+        (form_path.join("form.component.html"), "form.component.html"),
+        (form_path.join("form.component.ts"), "form.component.ts"),
+        (list_path.join("list.component.html"), "list.component.html"),
+        (list_path.join("list.component.ts"), "list.component.ts"),
+        (show_path.join("show.component.html"), "show.component.html"),
+        (show_path.join("show.component.ts"), "show.component.ts"),
+        // (modal_path.join("modal.component.html"), "modal.component.html"),
+        // (modal_path.join("modal.component.ts"), "modal.component.ts"),
+        (
+            base_path.join(format!("{}-routing.module.ts", &api_folder)),
+            "routing.module.ts",
+        ),
+        (
+            base_path.join(format!("{}.module.ts", &api_folder)),
+            "module.ts",
+        ),
+        (
+            service_path.join(format!(
+                "{}.resolver.ts",
+                kebab_case(&config.section_plural)
+            )),
+            "resolver.ts",
+        ),
+        (
+            service_path.join(format!("{}.service.ts", &api_folder)),
+            "service.ts",
+        ),
+        // (templates_path.join("form.interface.ts"), "form.interface.ts"),
+        // (templates_path.join("form.template.ts"), "form.template.ts"),
+        // (templates_path.join("list.temp.functions.ts"), "list.temp.functions.ts"),
+    ];
+    let pages = [
+        (
+            form_path.join("form-page.component.html"),
+            "form-page.component.html",
+        ),
+        (
+            form_path.join("form-page.component.ts"),
+            "form-page.component.ts",
+        ),
+        (
+            show_path.join("show-page.component.html"),
+            "show-page.component.html",
+        ),
+        (
+            show_path.join("show-page.component.ts"),
+            "show-page.component.ts",
+        ),
+    ];
+    let pages = if config.pages { &pages[..] } else { &[] };
+    for (path, template) in files.iter().chain(pages) {
+        plan.create(path.clone(), templates.render(template, &context)?);
+    }
 
-    let mut routing = File::create(format!(
-        "{}/{}-routing.module.ts",
-        &base_path, &config.api_route
-    ))?;
-    write_routing(
-        &mut routing,
-        model_name,
-        &config.section_plural,
-        plural_titled,
-        &config.section,
+    update_nav(
+        &mut plan,
+        &project.path(&project.nav),
+        &templates,
+        &context,
+        &model_name,
     )?;
-
-    let mut module = File::create(format!("{}/{}.module.ts", &base_path, &config.api_route))?;
-    write_module(
-        &mut module,
-        &config.section_plural,
-        plural_titled,
-        &config.api_route,
+    update_routes(
+        &mut plan,
+        &project.path(&project.routes),
+        &templates,
+        &context,
+        &model_name,
     )?;
-
-    let mut resolver = File::create(format!(
-        "{}/{}.resolver.ts",
-        &service_path, &config.section_plural
-    ))?;
-    write_resolvers(&mut resolver, model_name, plural_titled)?;
-
-    let mut service = File::create(format!(
-        "{}/{}.service.ts",
-        &service_path, &config.api_route
-    ))?;
-    write_model_service(
-        &mut service,
-        model_name,
-        &config.section,
-        &config.section_plural,
-        plural_titled,
+    update_route_enums(
+        &mut plan,
+        &project.path(&project.route_enums),
+        &model_name,
+        &url_path(&config.front_route),
+        &url_path(&config.api_route),
     )?;
 
-    update_nav(plural_titled, model_name, &config.icon)?;
-    update_routes(model_name, plural_titled, &config.api_route)?;
-    update_route_enums(model_name, &config.front_route, &config.api_route)?;
-
-    // let mut form_interface = File::create(format!("{}/form.interface.ts", &templates_path))?;
-    // write_form_interface(&mut form_interface, model_name)?;
-
-    // let mut form_template = File::create(format!("{}/form.template.ts", &templates_path))?;
-    // write_form_template(&mut form_template, &config.properties)?;
-    //
-    // let mut list_template = File::create(format!("{}/list.temp.functions.ts", &templates_path))?;
-    // write_list_template(
-    //     &mut list_template,
-    //     model_name,
-    //     &config.section,
-    //     &config.icon,
-    // )?;
-
-    let mut model_ts = File::create(format!(
-        "./src/app/shared/models/{}.model.ts",
-        &config.section
-    ))?;
-    write_model_ts(&mut model_ts, model_name, &config.properties)?;
-    Ok(())
+    plan.create(
+        project
+            .path(&project.models)
+            .join(format!("{}.model.ts", &section_file)),
+        templates.render("model.ts", &context)?,
+    );
+    Ok(plan)
 }
 
-fn write_model_ts(file: &mut File, model: &str, properties: &Properties) -> Result<(), Error> {
-    writeln!(
-        file,
-        r#"export interface {model} {{
-  id : number;
-  name : string;
-  /*
-   {:?}
-  */
-  created_at : Date;
-  updated_at: Date;
-}}
-"#,
-        properties
+/// Renders a single template for `config`, as `create` would, relating it to the models and
+/// services already read from the project.
+pub fn preview(
+    templates: &Templates,
+    config: &Config,
+    project: &Project,
+    models: &[ModelFile],
+    services: &[ServiceFile],
+    template: &str,
+) -> Result<String, Error> {
+    templates.render(
+        template,
+        &template_context(config, project, models, services),
     )
 }
 
-fn write_list_template(
-    file: &mut File,
-    model: &str,
-    section: &str,
-    icon: &str,
-) -> Result<(), Error> {
-    writeln!(
-        file,
-        r#"import {{ {model} }} from "../../../shared/models/{section}.model";
-import {{
-  MainListTemplate,
-  ListTemplate,
-  SpecialBlock,
-  ButtonAction,
-  Color,
-  ButtonType,
-}} from "../../../shared/models/basic/template.list.model";
-import {{ FontDataRender }} from "../../../shared/models/basic/fonts.template.const";
-
-export const template: MainListTemplate = {{
-  headIcon: "fas {icon} fa-2x ms-1 mt-1",
-}};
+fn template_context(
+    config: &Config,
+    project: &Project,
+    models: &[ModelFile],
+    services: &[ServiceFile],
+) -> Value {
+    let model_name = pascal_case(&config.section);
+    let section_file = kebab_case(&config.section);
+    let api_folder = kebab_case(&config.api_route);
+    let routes_dir = project.routes.parent().unwrap_or(Path::new(""));
+    let module_dir = project.views.join(&api_folder);
+    let pages_dir = module_dir.join("pages/form");
+    let service_dir = project.services.join(&api_folder);
+    let route_enums = project.route_enums.with_extension("");
+    let constants_dir = project.route_enums.parent().unwrap_or(Path::new(""));
+    let shared_dir = project.models.parent().unwrap_or(Path::new(""));
+    let root_service = project.services.join("root.service");
+    let service = service_dir.join(format!("{}.service", api_folder));
+    let relations = relations(config, project, models, services);
+    context! {
+        model => &model_name,
+        section => camel_case(&config.section),
+        section_file => &section_file,
+        section_label => lower_words(&config.section),
+        plural => camel_case(&config.section_plural),
+        plural_titled => pascal_case(&config.section_plural),
+        api_route => &api_folder,
+        front_route => url_path(&config.front_route),
+        icon => &config.icon,
+        nav_url_suffix => &project.nav_url_suffix,
+        list_path => project.nav_url_suffix.trim_start_matches('/'),
+        pages => config.pages,
+        views_import => relative_import(routes_dir, &project.views),
+        page_models_import => relative_import(&pages_dir, &project.models),
+        page_routes_import => relative_import(&pages_dir, &route_enums),
+        page_constants_import => relative_import(&pages_dir, constants_dir),
+        page_root_service_import => relative_import(&pages_dir, &root_service),
+        page_service_import => relative_import(&pages_dir, &service),
+        page_app_import => relative_import(&pages_dir, routes_dir),
+        module_routes_import => relative_import(&module_dir, &route_enums),
+        module_shared_import => relative_import(&module_dir, shared_dir),
+        module_app_import => relative_import(&module_dir, routes_dir),
+        service_models_import => relative_import(&service_dir, &project.models),
+        service_routes_import => relative_import(&service_dir, &route_enums),
+        service_root_service_import => relative_import(&service_dir, &root_service),
+        resolvers_import => format!(
+            "{}/{}.resolver",
+            relative_import(&module_dir, &service_dir),
+            kebab_case(&config.section_plural)
+        ),
+        fields => Value::from(Serde(entity_fields(&config.properties, &model_name, &relations))),
+        relations => Value::from(Serde(relations)),
+    }
+}
 
-export function setData(obj: {model}): ListTemplate {{
-  const  btnDestacado: ButtonAction = {{
-    name: obj.id ? "Destacado" : "No destacado",
-    icon: obj.id ? "fas fa-star" : '',
-    color: obj.id ? Color.warning : Color.medium,
-    type: ButtonType.chip,
-    tooltip: obj.id ? "No destacar" : "Destacar",
-    values: {{ name: "isDestacado", status: obj.id, id: obj.id }},
-  }};
-  return {{
-    nombreForDelete: `${{obj.id}}`,
-    avatar: "{icon}",
-    alerted:{{
-      message: 'Cod. '+obj.id,
-      color: Color.primary
-    }},
-    header: {{ header: "${{obj.id}}", fontEnum: FontDataRender.Header }},
-    personalEdit: true,
-    personalDelete: true,
-    id: obj.id,
-    specialButtons: [btnDestacado],
-    status: obj.id?`${{obj.id ? "true" : "false"}}`: undefined,
-    properties: [],
-  }};
-}}
-export function showSetData(obj: {model}): ListTemplate{{
-    return {{  }} as ListTemplate
-}}
-export function setValueQueryPlus(obj: {model}) {{
-  return {{  }};
-}}"#
+fn already_registered(path: &Path, model: &str) -> String {
+    format!(
+        "{} already registers RoutesEnum.{}, skipped",
+        path.display(),
+        model
     )
 }
 
-fn write_form_template(file: &mut File, properties: &Properties) -> Result<(), Error> {
-    writeln!(
-        file,
-        r#"import {{ Validators }} from "@angular/forms";
-import {{
-  InputTemplate,
-  InputTypes,
-}} from "../../../shared/models/basic/template.form.model";
-import {{ InitialState }} from "../../../root/root-form/inputs/inputs.component";
-/*
-{:?}
-*/
-
-export const initialState: {{ [x: string]: InitialState }} = {{
-  name: {{ value: null, disabled: false , validators: [Validators.required] }},
-}};
-
+fn read_source(path: &Path) -> Result<String, Error> {
+    read_to_string(path).map_err(|err| {
+        Error::new(
+            err.kind(),
+            format!("Could not read {}: {}", path.display(), err),
+        )
+    })
+}
 
-export const campsTemplate: Array<InputTemplate[]> = [
-  [
-    {{
-      name: "name",
-      class: "col-sm-6",
-      label: "Nombre Visible",
-      required: true,
-      typeinput: "text",
-    }},
-  ]
-];"#,
-        properties
+fn patch_error(path: &Path, err: String) -> Error {
+    Error::new(
+        ErrorKind::InvalidData,
+        format!("Could not patch {}: {}", path.display(), err),
     )
 }
 
-fn write_form_interface(file: &mut File, model: &str) -> Result<(), Error> {
-    writeln!(
-        file,
-        r#"import {{ FormInterface }} from "../../../root/root-form/form/form.component";
-
-export interface {model}Form extends FormInterface{{
-}}
-"#
-    )
+/// A property as seen by the templates.
+#[derive(Serialize)]
+struct FieldContext {
+    key: String,
+    kind: String,
+    label: String,
+    list: bool,
+    related: String,
+    related_file: String,
+    related_model: String,
+    many: bool,
+    cardinality: String,
+    display: String,
+    service: Option<RelatedService>,
+    enum_name: String,
+    options: Vec<EnumOption>,
+    /// Name of the form control, see `control_name`.
+    control: String,
+    /// Has a control, `HasMany` relations are only shown.
+    in_form: bool,
+    required: bool,
+    nullable: bool,
+    unique: bool,
+    readonly: bool,
+    /// TypeScript expression the form control starts with.
+    initial: String,
+    /// TypeScript validators of the form control.
+    validators: Vec<String>,
+    min: Option<String>,
+    max: Option<String>,
+    pattern: bool,
 }
 
-fn update_route_enums(model: &str, front_route: &str, api_route: &str) -> Result<(), Error> {
-    let mut route_enums = OpenOptions::new()
-        .write(true)
-        .open("./src/app/constants/routes.ts")?;
-    route_enums.seek(SeekFrom::End(-4))?;
-    writeln!(
-        route_enums,
-        r#"
-  {model} = "{front_route}",
-  {model}Api = "{api_route}",
-}}"#
-    )
+/// A member of the TypeScript string enum generated for an `Enum` property.
+#[derive(Serialize)]
+struct EnumOption {
+    member: String,
+    value: String,
 }
 
-fn update_routes(model: &str, plural_titled: &str, api_route: &str) -> Result<(), Error> {
-    let mut routes = OpenOptions::new()
-        .write(true)
-        .open("./src/app/_routes.ts")?;
-    routes.seek(SeekFrom::End(-4))?;
-    writeln!(
-        routes,
-        r#"
-  {{
-    path: RoutesEnum.{model},
-    loadChildren: () =>
-      import("./views/{api_route}/{api_route}.module").then(
-        (m) => m.{plural_titled}Module
-      ),
-  }},
-];"#
-    )
+/// A model the entity relates to, imported once however many properties point to it.
+#[derive(Serialize)]
+struct Relation {
+    model: String,
+    file: String,
+    /// Some property pointing to it is edited in the form, not only `HasMany` ones.
+    in_form: bool,
+    service: Option<RelatedService>,
 }
 
-fn update_nav(plural_titled: &str, model: &str, icon: &str) -> Result<(), Error> {
-    let mut nav = OpenOptions::new().write(true).open("./src/app/_nav.ts")?;
-    nav.seek(SeekFrom::End(-4))?;
-    writeln!(
-        nav,
-        r#"
-  {{
-    title: true,
-    name: RoutesEnum.{model},
-    class: "strong-font",
-    children: [
-      {{
-        name: RoutesEnum.{model},
-        url: `${{RoutesEnum.{model}}}/listar`,
-        icon: "fa {icon} scaling",
-        class: "strong-font",
-        active: RoutesEnum.{model},
-      }},
-    ],
-  }},
-];"#
-    )
+/// The service loading the options of a relation and the resolver that preloads them.
+#[derive(Clone, Serialize)]
+struct RelatedService {
+    class: String,
+    var: String,
+    nurture: String,
+    getter: String,
+    page_import: String,
+    resolver_import: String,
+    resolver: String,
 }
 
-fn write_resolvers(file: &mut File, model: &str, plural_titled: &str) -> Result<(), Error> {
-    writeln!(
-        file,
-        r#"import {{ Injectable }} from '@angular/core';
-import {{Resolve,
-  RouterStateSnapshot,
-  ActivatedRouteSnapshot
-}} from '@angular/router';
-import {{ Observable }} from 'rxjs';
-import {{ RoutesEnum }} from '../../../constants/routes';
-import {{ RootService }} from '../root.service';
+/// Models the `Related` properties point to, with the file and service found for each one in
+/// the project. The entity being generated can relate to itself.
+fn relations(
+    config: &Config,
+    project: &Project,
+    models: &[ModelFile],
+    services: &[ServiceFile],
+) -> Vec<Relation> {
+    let model_name = pascal_case(&config.section);
+    let api_folder = kebab_case(&config.api_route);
+    let own_service = ServiceFile {
+        model: model_name.clone(),
+        path: project
+            .services
+            .join(&api_folder)
+            .join(format!("{}.service.ts", api_folder)),
+        nurture: format!("nurture{}", pascal_case(&config.section_plural)),
+    };
+    let mut relations: Vec<Relation> = Vec::new();
+    for (key, field) in &config.properties {
+        let target = related_model(&identifier(key), field);
+        if field.kind != Property::Related || relations.iter().any(|rel| rel.model == target) {
+            continue;
+        }
+        let in_form = config.properties.iter().any(|(other_key, other)| {
+            other.kind == Property::Related
+                && other.cardinality.is_edited()
+                && related_model(&identifier(other_key), other) == target
+        });
+        let (file, service) = if target == model_name {
+            (kebab_case(&config.section), Some(&own_service))
+        } else {
+            (
+                models
+                    .iter()
+                    .find(|model| model.name == target)
+                    .map(|model| model.file.clone())
+                    .unwrap_or_else(|| kebab_case(&target)),
+                services.iter().find(|service| service.model == target),
+            )
+        };
+        relations.push(Relation {
+            service: service
+                .filter(|_| in_form)
+                .map(|service| related_service(service, config, project)),
+            model: target,
+            file,
+            in_form,
+        });
+    }
+    relations
+}
 
-@Injectable({{
-  providedIn: 'root'
-}})
-export class All{plural_titled}Resolver implements Resolve<unknown> {{
-  constructor(private rootService:RootService){{}}
-  resolve(route: ActivatedRouteSnapshot, state: RouterStateSnapshot): Observable<unknown> {{
-    return this.rootService.getAll(RoutesEnum.{model}Api)
-  }}
-}}
-@Injectable({{
-  providedIn: 'root'
-}})
-export class {model}Resolver implements Resolve<unknown> {{
-  constructor(private rootService:RootService){{}}
-  resolve(route: ActivatedRouteSnapshot, state: RouterStateSnapshot): Observable<unknown> {{
-    const{{id}} = route.params
-    return this.rootService.getOne(RoutesEnum.{model}Api,id)
-  }}
-}}
-@Injectable({{
-  providedIn: 'root'
-}})
-export class {plural_titled}Resolver implements Resolve<unknown> {{
-  constructor(private rootService:RootService){{}}
-  resolve(route: ActivatedRouteSnapshot, state: RouterStateSnapshot): Observable<unknown> {{
-    const {{take,page,search}} = route.queryParams
-    return this.rootService.index('productos/rubros',page,take,search)
-  }}
-}}
-"#
-    )
+fn related_service(service: &ServiceFile, config: &Config, project: &Project) -> RelatedService {
+    let api_folder = kebab_case(&config.api_route);
+    let stem = service.path.with_extension("");
+    let dir = stem.parent().unwrap_or(Path::new(""));
+    let file = stem.file_name().unwrap_or_default().to_string_lossy();
+    let import = |from: &Path| format!("{}/{}", relative_import(from, dir), file);
+    RelatedService {
+        class: format!("{}Service", service.model),
+        var: format!("{}Service", camel_case(&service.model)),
+        nurture: service.nurture.clone(),
+        getter: camel_case(service.nurture.trim_start_matches("nurture")),
+        page_import: import(&project.views.join(&api_folder).join("pages/form")),
+        resolver_import: import(&project.services.join(&api_folder)),
+        resolver: format!(
+            "{}{}OptionsResolver",
+            pascal_case(&config.section_plural),
+            service.model
+        ),
+    }
 }
 
-fn write_routing(
-    file: &mut File,
+/// Properties of the entity plus the `name` display field every template relies on.
+fn entity_fields(
+    properties: &Properties,
     model: &str,
-    section_plural: &str,
-    plural_titled: &str,
-    section: &str,
-) -> Result<(), Error> {
-    writeln!(
-        file,
-        r#"import {{ NgModule }} from "@angular/core";
-import {{ Routes, RouterModule }} from "@angular/router";
-//import {{ Modal{plural_titled}Resolver }} from "../../constants/resolvers";
-import {{ RoutesEnum }} from "../../constants/routes";
-import {{ FormComponent }} from "./pages/form/form.component";
-import {{ ListComponent }} from "./pages/list/list.component";
-import {{ ShowComponent }} from "./pages/show/show.component";
-
-const section = RoutesEnum.{model};
-const apiSection = RoutesEnum.{model}Api;
-
-const routes: Routes = [
-  {{
-    path: "",
-    data: {{
-      title: "{plural_titled}",
-    }},
-    children: [
-      {{
-        path: "listar",
-        component: ListComponent,
-        data: {{
-          section,
-          apiSection,
-          modalComponent: FormComponent,
-          showComponent: ShowComponent,
-      //  resolvers:{{
-      //    {section_plural}: Modal{plural_titled}Resolver,
-      //  }}
-        }},
-      }},
-    ],
-  }},
-];
-
-@NgModule({{
-  imports: [RouterModule.forChild(routes)],
-  exports: [RouterModule],
-}})
-export class {plural_titled}RoutingModule {{}}
-export const routingComponents = [FormComponent, ListComponent, ShowComponent];
-"#
-    )
+    relations: &[Relation],
+) -> Vec<FieldContext> {
+    let mut fields = Vec::new();
+    if !properties.contains_key("name") {
+        let mut name = field_context("name", &Field::new(Property::String), model, relations);
+        // The generated UI is in Spanish, key-derived labels only fit declared properties.
+        name.label = "Nombre".to_owned();
+        fields.push(name);
+    }
+    for (key, field) in properties {
+        fields.push(field_context(&identifier(key), field, model, relations));
+    }
+    fields
 }
-fn write_module(
-    file: &mut File,
-    section_plural: &str,
-    plural_titled: &str,
-    api_route: &str,
-) -> Result<(), Error> {
-    writeln!(
-        file,
-        r#"import {{ CommonModule }} from '@angular/common';
-import {{ NgModule }} from '@angular/core';
-import {{ ReactiveFormsModule, FormsModule }} from '@angular/forms';
-import {{ PaginationModule }} from 'ngx-bootstrap/pagination';
-import {{ SharedModule }} from '../../shared/shared.module';
-import {{ {plural_titled}RoutingModule, routingComponents }} from './{api_route}-routing.module';
-import {{ RootModule }} from '../../root/root.module';
-import {{ MatPaginatorModule }} from '@angular/material/paginator';
-import {{ HeaderListadoModule }} from '../../shared/components/header-listado/header-listado.module';
-import {{ MatDialogModule }} from '@angular/material/dialog';
-import {{ MatFormFieldModule }} from '@angular/material/form-field';
-import {{ MatInputModule }} from '@angular/material/input';
-import {{ MatButtonModule }} from '@angular/material/button';
-import {{ StatusModule }} from '../../shared/components/buttons/status/status.module';
-import {{ EditModule }} from '../../shared/components/buttons/edit/edit.module';
-import {{ DeleteModule }} from '../../shared/components/buttons/delete/delete.module';
 
-@NgModule({{
-  imports: [
-    {plural_titled}RoutingModule,
-    ReactiveFormsModule,
-    CommonModule,
-    PaginationModule,
-    FormsModule,
-    SharedModule,
-    RootModule,
-    MatPaginatorModule,
-    MatDialogModule,
-    HeaderListadoModule,
-    MatFormFieldModule,
-    MatInputModule,
-    MatButtonModule,
-    StatusModule,
-    EditModule,
-    DeleteModule
-  ],
-  declarations: routingComponents
-}})
-export class {plural_titled}Module {{ }}
-"#
-    )
-}
-fn write_form_html(file: &mut File, section: &str) -> Result<(), Error> {
-    writeln!(
-        file,
-        r#"<div class="container">
-    <h6 mat-dialog-title class="text-primary">{{{{title}}}}</h6>
-    <mat-dialog-content>
-      <form [formGroup]="form">
-        <div class="row w-100 p-0 m-0">
-          <div class="col-12">
-            <mat-form-field class="w-100" appearance="outline">
-              <mat-label> Nombre </mat-label>
-              <input matInput formControlName="name" />
-            </mat-form-field>
-          </div>
-        </div>
-      </form>
-    </mat-dialog-content>
-    <div
-      mat-dialog-actions
-      align="center"
-      class="m-0 p-0 d-flex justify-content-evenly"
-    >
-      <button
-        mat-flat-button
-        color="warn"
-        type="button"
-        (click)="close()"
-      >
-        Cancelar
-      </button>
-  
-      <button mat-flat-button color="primary" type="button" (click)="submit()" [disabled]="form.invalid">
-        Guardar
-      </button>
-    </div>
-  </div>"#
-    )
+fn field_context(key: &str, field: &Field, model: &str, relations: &[Relation]) -> FieldContext {
+    let related = related_name(key);
+    let related_model = related_model(key, field);
+    let relation = relations.iter().find(|rel| rel.model == related_model);
+    let many = field.kind == Property::Related && field.cardinality.is_many();
+    let enum_name = format!("{}{}", model, pascal_case(key));
+    FieldContext {
+        key: key.to_owned(),
+        kind: field.kind.to_string(),
+        label: sentence_case(related),
+        list: field.list,
+        related: related.to_owned(),
+        related_file: relation.map_or_else(|| kebab_case(related), |rel| rel.file.clone()),
+        related_model,
+        many,
+        cardinality: field.cardinality.to_string(),
+        display: field.display.clone(),
+        service: relation.and_then(|rel| rel.service.clone()),
+        control: control_name(key, field),
+        in_form: field.kind != Property::Related || field.cardinality.is_edited(),
+        required: field.required,
+        nullable: field.nullable,
+        unique: field.unique,
+        readonly: field.readonly,
+        initial: initial_value(field, &enum_name),
+        validators: validators(field),
+        min: field.min.map(|min| min.to_string()),
+        max: field.max.map(|max| max.to_string()),
+        pattern: field.pattern.is_some(),
+        enum_name,
+        options: field
+            .values
+            .iter()
+            .map(|value| EnumOption {
+                member: pascal_case(value),
+                value: value.to_owned(),
+            })
+            .collect(),
+    }
 }
-fn write_form_ts(file: &mut File, section: &str, model: &str) -> Result<(), Error> {
-    writeln!(
-        file,
-        r#"import {{ HttpErrorResponse }} from "@angular/common/http";
-import {{ Component, Inject, OnInit }} from "@angular/core";
-import {{ Validators, FormBuilder }} from "@angular/forms";
-import {{ MatDialogRef, MAT_DIALOG_DATA }} from "@angular/material/dialog";
-import {{ MatSnackBar }} from "@angular/material/snack-bar";
-import {{ Observable }} from "rxjs";
-import {{ RoutesEnum }} from "../../../../constants/routes";
-import {{ RootService }} from "../../../../services/http/root.service";
-import {{ {model} }} from "../../../../shared/models/{section}.model";
-
-@Component({{
-  templateUrl: "form.component.html",
-  styleUrls: ["../../../../app.component.css"],
-}})
-export class FormComponent implements OnInit {{
-  form = this.fb.group({{
-    name: ["", Validators.required],
-  }});
-  {section}?: {model};
-
-  constructor(
-    @Inject (MAT_DIALOG_DATA)public data:any,
-    private rootService: RootService,
-    private snackbar: MatSnackBar,
-    private fb: FormBuilder,
-    private dialog: MatDialogRef<FormComponent>
-  ) {{
-    this.getEntity();
-  }}
-
-  protected getEntity(): void {{
-    if (this.data) {{
-      const {{ entityForEdit }} = this.data;
-      this.{section} = entityForEdit;
-    }}
-  }}
-  ngOnInit(): void {{
-    if (this.{section}) {{
-      this.form.reset({{
-        name: this.{section}.name,
-      }});
-    }}
-  }}
-
-  close(): void {{
-    this.dialog.close();
-  }}
-
-  submit(): void {{
-    // console.log(this.form.value);
-    if (this.{section}) {{
-      this.edit();
-    }} else {{
-      this.create();
-    }}
-  }}
-  create() {{
-    this.rootService
-      .create(RoutesEnum.{model}Api, this.form.value)
-      .subscribe({{
-        next: (resp) => {{
-          this.snackbar.open(`{model} creado correctamente.`, "Ok", {{
-            duration: 2500,
-            panelClass: ["primary-snackbar"],
-            verticalPosition: "top",
-            horizontalPosition: "end",
-          }});
-          this.dialog.close({{ listChange: true }});
-        }},
-        error: (err: HttpErrorResponse) => {{
-          this.snackbar.open(
-            "No pudimos crear el {model}. " + `(${{err.status}})`,
-            "Ok",
-            {{
-              panelClass: ["error-snackbar"],
-              verticalPosition: "top",
-              horizontalPosition: "end",
-            }}
-          );
-          this.dialog.close({{ listChange: false }});
-        }},
-      }});
-  }}
-  edit(): void {{
-    if (this.{section}) {{
-      this.rootService
-        .update(
-          RoutesEnum.{model}Api,
-          {{ ...this.form.value, id: this.{section}.id }},
-          this.{section}.id
-        )
-        .subscribe({{
-          next: (resp) => {{
-            this.snackbar.open(
-              `${{this.form.get("name")?.value}} editado correctamente.`,
-              "Ok",
-              {{
-                duration: 2500,
-                panelClass: ["primary-snackbar"],
-                verticalPosition: "top",
-                horizontalPosition: "end",
-              }}
-            );
-            this.dialog.close({{ listChange: true }});
-          }},
-          error: (err: HttpErrorResponse) => {{
-            this.snackbar.open(
-              "No se completó el proceso de editado. " + `(${{err.status}})`,
-              "Ok",
-              {{
-                panelClass: ["error-snackbar"],
-                verticalPosition: "top",
-                horizontalPosition: "end",
-              }}
-            );
-            this.dialog.close({{ listChange: false }});
-          }},
-        }});
-    }}
-  }}
 
-  get title(): string {{
-    return this.{section}
-      ? `Editando ${{this.{section}.name}}`
-      : "Creando nuevo {section}";
-  }}
-}}
-"#
-    )
+/// The default of the property as a TypeScript literal, or the empty value of its kind.
+fn initial_value(field: &Field, enum_name: &str) -> String {
+    if field.kind == Property::Related && field.cardinality.is_many() {
+        return "[]".to_owned();
+    }
+    match (&field.default, field.kind) {
+        (Some(default), Property::Enum) => format!("{}.{}", enum_name, pascal_case(default)),
+        (Some(default), Property::Date) => format!("new Date({:?})", default),
+        (Some(default), kind) if kind.is_text() => {
+            serde_json::to_string(default).expect("strings always serialize")
+        }
+        (Some(default), _) => default.to_owned(),
+        (None, Property::Boolean) => "false".to_owned(),
+        (None, kind) if kind.is_text() && !field.nullable => "\"\"".to_owned(),
+        (None, _) => "null".to_owned(),
+    }
 }
 
-fn write_list_html(file: &mut File, plural: &str) -> Result<(), Error> {
-    writeln!(
-        file,
-        r#"<div #topScrollAnchor></div>
-<div class="row wrapp-all animated fadeIn">
-  <div class="col-sm-12">
-    <div class="row">
-      <div class="col-12 col-lg-5 mb-2">
-        <label class="form-title pt-0">
-          <span class="d-none d-xl-inline"> Listado de </span>
-          {{{{ title }}}}
-        </label>
-      </div>
-
-      <div class="col-12 col-lg-7 mb-2">
-        <div class="row">
-          <div class="col-12 col-sm-6 mb-2">
-            <app-shared-searcher (valueSearch)="search($event)">
-            </app-shared-searcher>
-          </div>
-          <div class="col-12 col-sm-6">
-            <app-header-listado></app-header-listado>
-          </div>
-        </div>
-      </div>
+fn validators(field: &Field) -> Vec<String> {
+    let mut validators = Vec::new();
+    if field.required && field.kind != Property::Boolean {
+        validators.push("Validators.required".to_owned());
+    }
+    match field.kind {
+        Property::Email => validators.push("Validators.email".to_owned()),
+        Property::Url => validators.push(r"Validators.pattern(/^https?:\/\/\S+$/)".to_owned()),
+        Property::Phone => {
+            validators.push(r"Validators.pattern(/^\+?[0-9 ()-]{6,20}$/)".to_owned())
+        }
+        _ => {}
+    }
+    let (min, max) = if field.kind == Property::Number {
+        ("min", "max")
+    } else {
+        ("minLength", "maxLength")
+    };
+    if let Some(limit) = field.min {
+        validators.push(format!("Validators.{}({})", min, limit));
+    }
+    if let Some(limit) = field.max {
+        validators.push(format!("Validators.{}({})", max, limit));
+    }
+    if let Some(pattern) = &field.pattern {
+        validators.push(format!(
+            "Validators.pattern({})",
+            serde_json::to_string(pattern).expect("strings always serialize")
+        ));
+    }
+    validators
+}
 
-      <div class="col-12">
-        <simple-card
-          *ngFor="let ent of {plural}List$ | async; let i = index"
-          [id]="'card' + i"
-          [ngClass]="ent.status === false ? 'fade-out-left' : ''"
-        >
-          <div
-            id="main-row"
-            class="row bg-white text-medium mb-2 p-1 pt-2 ps-2 pe-0 rounded"
-          >
-            <div class="col-auto ps-0 pe-0">
-              <h6
-                class="breakWord-blank m-0 text-black-75 w-100"
-                style="font-size: 0.95rem"
-              >
-                {{{{ ent.name | titlecase }}}}
-              </h6>
-            </div>
+/// Name of the form control and model field holding the value, `{related}_id` for a
+/// `BelongsTo` and `{target}_ids` for a many relation, as `tags` pointing to `Tag` is `tag_ids`.
+fn control_name(key: &str, field: &Field) -> String {
+    match field.kind {
+        Property::Related if field.cardinality.is_many() => match &field.target {
+            Some(target) => format!("{}_ids", snake_case(target)),
+            None => format!("{}_ids", related_name(key)),
+        },
+        Property::Related => format!("{}_id", related_name(key)),
+        _ => key.to_owned(),
+    }
+}
 
-            <div class="col-sm-12 ps-0">
-              <div class="row w-100 justify-content-end">
-                <div class="col-auto">
-                  <div class="row">
-                    <div class="col-auto" *ngIf="ent?.created_at">
-                      <small class="m-0 p-0 text-black-75 fst-italic"
-                        >Creado: </small
-                      ><small class="m-0 p-0 text-medium fw-bold">{{{{
-                        ent?.created_at | date: "medium"
-                      }}}}</small>
-                    </div>
-                    <div class="col-auto" *ngIf="ent?.updated_at">
-                      <small class="m-0 p-0 text-black-75 fst-italic"
-                        >Modificado: </small
-                      ><small class="m-0 p-0 text-medium fw-bold">{{{{
-                        ent?.updated_at | date: "medium"
-                      }}}}</small>
-                    </div>
-                  </div>
-                </div>
-                <div
-                  class="col d-flex justify-content-end align-items-center p-0"
-                >
-                  <div class="row d-flex justify-content-end">
-                    <div class="col-1">
-                      <status-button
-                        [id]="ent.id"
-                        [statusToggle]="{{ status: ent.status }}"
-                        [apiSection]="pathDelete ?? apiSection ?? ''"
-                        [index]="i"
-                        (switch)="disableCard($event)"
-                      ></status-button>
-                    </div>
-                    <div class="col-1">
-                      <edit-button
-                        [id]="ent.id"
-                        [form]="formComponentEdit"
-                      ></edit-button>
-                    </div>
-                    <div class="col-1">
-                      <delete-button
-                        [config]="{{
-                          id: ent.id,
-                          nameToDelete: ent.name,
-                          section: apiRoute 
-                        }}"
-                        (listChange)="listChange($event)"
-                      ></delete-button>
-                    </div>
-                  </div>
-                </div>
-              </div>
-            </div>
-          </div>
-        </simple-card>
-      </div>
+fn related_name(key: &str) -> &str {
+    key.strip_suffix("_ids")
+        .or_else(|| key.strip_suffix("_id"))
+        .unwrap_or(key)
+}
 
-      <div class="col-12" *ngIf="({plural}List$ | async )?.length">
-        <mat-paginator
-          color="primary"
-          [length]="totalItems"
-          [pageIndex]="currentPage"
-          [pageSize]="itemsPerPage"
-          [pageSizeOptions]="defaultTakeOptions"
-          aria-label="Elegir página"
-          showFirstLastButtons
-          (page)="changePage($event)"
-        >
-        </mat-paginator>
-      </div>
-    </div>
-  </div>
-</div>
-"#
-    )
+/// The target of a `Related` property, or the model named after its key.
+fn related_model(key: &str, field: &Field) -> String {
+    field
+        .target
+        .as_deref()
+        .map_or_else(|| pascal_case(related_name(key)), pascal_case)
 }
-fn write_list_ts(
-    file: &mut File,
+
+fn update_route_enums(
+    plan: &mut Plan,
+    path: &Path,
     model: &str,
-    section: &str,
-    plural_titled: &str,
-    plural: &str,
+    front_route: &str,
     api_route: &str,
 ) -> Result<(), Error> {
-    writeln!(
-        file,
-        r#"import {{ ComponentType }} from "@angular/cdk/portal";
-import {{ Component, OnInit }} from "@angular/core";
-import {{ MatDialog }} from "@angular/material/dialog";
-import {{ ActivatedRoute, Router }} from "@angular/router";
-import {{ delay, filter, Observable, switchMap }} from "rxjs";
-import {{ InteractionsId }} from "../../../../constants/interactions";
-import {{ RoutesEnum }} from "../../../../constants/routes";
-import {{ UserRole }} from "../../../../constants/userRoles";
-import {{ ListBaseComponent }} from "../../../../root/root-list/list-base/list-base.component";
-import {{ {model}Service }} from "../../../../services/http/{api_route}/{api_route}.service";
-import {{ RootService }} from "../../../../services/http/root.service";
-import {{ InteractionService, InteractionSource }} from "../../../../services/interaction/interaction.service";
-import {{ LoginService }} from "../../../../services/login/login.service";
-import {{ ModalService }} from "../../../../services/modals/modals.service";
-import {{ ArrayResponse }} from "../../../../shared/models/basic/res.array.model";
-import {{ FormComponent }} from "../form/form.component";
-import {{ {model} }} from "../../../../shared/models/{section}.model";
-
-@Component({{
-  selector: "app-list",
-  templateUrl: "./list.component.html",
-  styleUrls: ["../../../../app.component.css"],
-}})
-export class ListComponent extends ListBaseComponent implements OnInit {{
-  constructor(
-    private logService: LoginService,
-    activatedRoute: ActivatedRoute,
-    rootService: RootService,
-    router: Router,
-    modalService: ModalService,
-    interaction: InteractionService,
-    dialog: MatDialog,
-    private {section}Service: {model}Service
-  ) {{
-    super(activatedRoute, router, rootService, modalService, interaction, dialog);
-  }}
-  userRole?: UserRole;
-  userId?: string|null;
-  {plural}List$?: Observable<{model}[]>;
-  formComponentEdit: ComponentType<FormComponent> = FormComponent;
-  routeEnum = RoutesEnum.{model}Api;
-
-  ngOnInit() {{
-    this.{plural}List$ = this.{section}Service.{plural};
-    this.subscriptions.add(
-      this.activatedRoute.queryParams.subscribe((query)=>{{
-        const {{page, take, search}} = query;
-        this.queryParams.queryParams={{page, take, search}};
-        this.rootService.index<{model}>(this.apiSection!, page, take, search)
-        .subscribe((res:ArrayResponse<{model}>)=>{{
-          this.{section}Service.set{plural_titled}(res.data);
-          this.setPage(res);
-        }})
-      }})
-    );
-    this.subscriptions.add(
-      this.interactionService.interaction
-        .pipe(
-          filter(
-            (e) =>
-              (e.source === InteractionSource.button &&
-              e.id === InteractionsId.listChange) || (e.id === InteractionsId.RootForm)
-          ),
-          delay(800)
-        )
-        .subscribe((res) => this.listChange())
-    );
-  }}
-
-  listChange(e?: any): void {{
-    this.rootService
-      .index<{model}>(this.apiSection ?? '')
-      .pipe(switchMap(async (res) => this.{section}Service.set{plural_titled}(res.data)))
-      .subscribe();
-    this.dialog.closeAll();
-  }}
-  
-  disableCard(e: any): void {{
-    if (e.status === 0) {{
-      document
-        .getElementById("card" + e.i)
-        ?.classList.replace("fade-in", "fade-out-left");
-    }} else {{
-      document
-        .getElementById("card" + e.i)
-        ?.classList.replace("fade-out-left", "fade-in");
-    }}
-  }}
-}}
-"#
-    )
-}
-fn write_show_html(file: &mut File) -> Result<(), Error> {
-    writeln!(
-        file,
-        r#"<app-root-show
-    [forShow]="showing"
->
-</app-root-show>
-"#
+    let original = read_source(path)?;
+    let api_model = format!("{model}Api");
+    let (route_enums, updated) = upsert_enum_members(
+        &original,
+        "RoutesEnum",
+        &[(model, front_route), (&api_model, api_route)],
     )
+    .map_err(|err| patch_error(path, err))?;
+    for member in updated {
+        plan.note(format!("Updated {} in {}", member, path.display()));
+    }
+    plan.patch(path, original, route_enums);
+    Ok(())
 }
-fn write_show_ts(file: &mut File, model: &str, section: &str) -> Result<(), Error> {
-    writeln!(
-        file,
-        r#"import {{ Component, Inject }} from "@angular/core";
-import {{ MAT_DIALOG_DATA }} from "@angular/material/dialog";
-import {{ ModalShowBaseComponent }} from "../../../../root/root-show/modal-show-base/modal-show-base.component";
-import {{ DataModalShow }} from "../../../../shared/models/basic/data.routes.model";
-import {{ {model} }} from "../../../../shared/models/{section}.model";
 
-@Component({{
-    templateUrl: "./show.component.html",
-  }})
-  export class ShowComponent extends ModalShowBaseComponent {{
-    constructor(
-    @Inject(MAT_DIALOG_DATA) data: DataModalShow<{model}>
-    ){{
-        super(data)
-    }}
-  }}
-"#
-    )
-}
-fn write_modal_html(file: &mut File, section: &str) -> Result<(), Error> {
-    writeln!(
-        file,
-        r#"<mat-dialog-content>
-    <app-root-form 
-        #form
-        [template]="campsTemplate!"
-        [title]="title"
-        [forEdit]="{section}ForEdit"
-        [initialStateControls]="initialState"
-        [selectOptions]="optionsPassed"
-        [section]="section!"
-        [APIroute]="apiSection!"
-        [isModal]="true"
-        (close)="close($event)"
-    >
-    </app-root-form>
-</mat-dialog-content>"#
-    )
+fn update_routes(
+    plan: &mut Plan,
+    path: &Path,
+    templates: &Templates,
+    context: &Value,
+    model: &str,
+) -> Result<(), Error> {
+    let original = read_source(path)?;
+    if array_references(&original, "RoutesEnum", model).map_err(|err| patch_error(path, err))? {
+        plan.note(already_registered(path, model));
+        return Ok(());
+    }
+    let routes = insert_array_entry(&original, &templates.render("route.entry.ts", context)?)
+        .map_err(|err| patch_error(path, err))?;
+    plan.patch(path, original, routes);
+    Ok(())
 }
-fn write_modal_ts(file: &mut File, model: &str, section: &str) -> Result<(), Error> {
-    writeln!(
-        file,
-        r#"import {{ Component, Inject, OnInit, AfterViewInit }} from "@angular/core";
-import {{ ChangeDetectorRef }} from "@angular/core";
-import {{ LoginService }} from "../../../../services/login/login.service";
-import {{ MatDialog, MatDialogRef, MAT_DIALOG_DATA }} from "@angular/material/dialog";
-import {{ ModalFormBaseComponent }} from "../../../../root/root-form/modal-form-base/modal-form-base.component";
-import {{ DataEditForm }} from "../../../../shared/models/basic/data.routes.model";
-import {{ RootService }} from "../../../../services/http/root.service";
-import {{ InteractionService }} from "../../../../services/interaction/interaction.service";
-import {{ {model} }} from "../../../../shared/models/{section}.model";
-import {{ {model}Form }} from "../../templates/form.interface";
-
-@Component({{
-  templateUrl: "modal.component.html",
-  styleUrls: ["../../../../app.component.css"],
-}})
-export class ModalComponent extends ModalFormBaseComponent implements {model}Form, OnInit, AfterViewInit {{
-  constructor(
-    private cdr: ChangeDetectorRef,
-    dialogRef: MatDialogRef<ModalComponent>,
-    logService: LoginService,
-    interaction: InteractionService,
-    dialog: MatDialog,
-    rootService: RootService,
-    @Inject(MAT_DIALOG_DATA) data: DataEditForm
-  ) {{
-    super(dialogRef, logService, data, interaction, dialog, rootService);
-  }}
-  override activoInactivoOptions: any[] = [
-{{value: "destacado", description: "Destacado" }},
-{{value: "estado", description: "Activo" }},
-  ];
-  
-{section}ForEdit?: {model};
 
-  ngOnInit() {{
-    this.setStartingValues();
-  }}
-
-  setStartingValues(){{
-    // const {{ {section} }} = this.data.resolvers!;
-    this.optionsPassed["opciones"] = this.activoInactivoOptions; // Cada Campo usa su name como respectiva key
-    this.{section}ForEdit = this.data.entityForEdit;
-    if (this.{section}ForEdit) {{
-    // this.{section}ForEdit.opciones = [];
-    // if (this.{section}ForEdit?.estado){{
-    //   this.{section}ForEdit.opciones.push("estado");
-    // }}
-    }}
-  }}
-  
-  ngAfterViewInit(): void {{
-    //ACA HACER USO DEL FORM
-    this.inputsControls = this.form?.inputs.toArray();
-  }}
-}}
-"#
-    )
-}
-fn write_model_service(
-    file: &mut File,
+fn update_nav(
+    plan: &mut Plan,
+    path: &Path,
+    templates: &Templates,
+    context: &Value,
     model: &str,
-    section: &str,
-    plural: &str,
-    plural_titled: &str,
 ) -> Result<(), Error> {
-    writeln!(
-        file,
-        r#"import {{ Injectable }} from "@angular/core";
-import {{ BehaviorSubject, Observable, Subject, tap }} from "rxjs";
-import {{ RoutesEnum }} from "../../../constants/routes";
-import {{ {model} }} from "../../../shared/models/{section}.model";
-import {{ RootService }} from "../root.service";
-
-@Injectable({{
-  providedIn: "root",
-}})
-export class {model}Service {{
-  #{section}: Subject<{model}> = new Subject<{model}>();
-  #{plural}: BehaviorSubject<{model}[]> = new BehaviorSubject<{model}[]>([]);
-
-  constructor(private rootService: RootService) {{
-    this.#{section}.subscribe((col) =>
-      this.#{plural}.next([...this.#{plural}.value, col])
-    );
-  }}
-
-  get {section} 
-  (): Observable<{model}> {{
-    return this.#{section}.asObservable();
-  }}
-
-  get {plural}(): Observable<{model}[]> {{
-    return this.#{plural}.asObservable();
-  }}
-
-  set{plural_titled}({plural}: {model}[]): void {{
-    this.#{plural}.next({plural});
-  }}
-
-  public nurture{plural_titled}(): Observable<{model}[]> {{
-    return this.rootService
-      .getAllActive<{model}[]>(RoutesEnum.{model}Api)
-      .pipe(tap(({plural}) => this.set{plural_titled}({plural})));
-  }}
-
-  emit{model}({section}: {model}): void {{
-    this.#{section}.next({section});
-  }}
-}}
-"#
-    )
+    let original = read_source(path)?;
+    if array_references(&original, "RoutesEnum", model).map_err(|err| patch_error(path, err))? {
+        plan.note(already_registered(path, model));
+        return Ok(());
+    }
+    let nav = insert_array_entry(&original, &templates.render("nav.entry.ts", context)?)
+        .map_err(|err| patch_error(path, err))?;
+    plan.patch(path, original, nav);
+    Ok(())
 }
@@ -0,0 +1,588 @@
+//! Full-screen editor for the same `Config` the wizard builds: entity metadata, a property
+//! table edited in place and a preview of the generated files that follows every change.
+
+use crate::create::{preview, Config};
+use crate::input_data::{cancelled, require_terminal};
+use crate::models::{Field, Property};
+use crate::naming::pascal_case;
+use crate::plural::pluralize;
+use crate::project::{ModelFile, Project, ServiceFile};
+use crate::templates::Templates;
+use crate::validate::{
+    validate_enum_values, validate_modifiers, validate_name, validate_property, validate_route,
+};
+use crate::Properties;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Paragraph, Row, Table, TableState, Tabs};
+use ratatui::{DefaultTerminal, Frame};
+use std::io::Error;
+
+const METADATA: [&str; 5] = [
+    "Section",
+    "Section plural",
+    "Front route",
+    "API route",
+    "Icon",
+];
+const PREVIEWS: [&str; 4] = [
+    "model.ts",
+    "form.component.html",
+    "form.component.ts",
+    "list.component.html",
+];
+const COLUMNS: [&str; 4] = ["Name", "Type", "Listed", "Values"];
+
+#[derive(Clone, Copy, PartialEq)]
+enum Pane {
+    Metadata,
+    Properties,
+    Preview,
+}
+
+/// Text being typed into a metadata value or a property name.
+struct Edit {
+    buffer: String,
+    /// Property row being added, dropped if the edit is cancelled.
+    new_row: bool,
+}
+
+struct Designer<'a> {
+    project: &'a Project,
+    templates: Templates,
+    /// Read once, the preview is rendered on every redraw.
+    models: Vec<ModelFile>,
+    services: Vec<ServiceFile>,
+    metadata: [String; 5],
+    properties: Vec<(String, Field)>,
+    pane: Pane,
+    metadata_row: usize,
+    table: TableState,
+    preview: usize,
+    scroll: u16,
+    edit: Option<Edit>,
+    status: String,
+    pages: bool,
+}
+
+/// Runs the designer until the user generates (returning the config) or quits.
+pub fn design(
+    project: &Project,
+    metadata: [String; 5],
+    properties: Properties,
+    pages: bool,
+) -> Result<Config, Error> {
+    require_terminal()?;
+    let mut designer = Designer {
+        project,
+        templates: Templates::new(project.templates()),
+        models: project.models(),
+        services: project.services(),
+        metadata,
+        properties: properties.into_iter().collect(),
+        pane: Pane::Metadata,
+        metadata_row: 0,
+        table: TableState::default()
+            .with_selected(0)
+            .with_selected_column(0),
+        preview: 0,
+        scroll: 0,
+        edit: None,
+        status: String::new(),
+        pages,
+    };
+    designer.fill_defaults();
+    let mut terminal = ratatui::try_init()?;
+    let result = designer.run(&mut terminal);
+    ratatui::try_restore()?;
+    result
+}
+
+impl Designer<'_> {
+    fn run(&mut self, terminal: &mut DefaultTerminal) -> Result<Config, Error> {
+        loop {
+            terminal.draw(|frame| self.draw(frame))?;
+            let Event::Key(key) = event::read()? else {
+                continue;
+            };
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+            let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+            if ctrl && key.code == KeyCode::Char('c') {
+                return Err(cancelled());
+            }
+            if ctrl && key.code == KeyCode::Char('s') {
+                // A cell being edited is saved first, as Enter would.
+                if self.edit.is_some() {
+                    if let Err(message) = self.commit() {
+                        self.status = message;
+                        continue;
+                    }
+                    self.edit = None;
+                }
+                match self.validated() {
+                    Ok(config) => return Ok(config),
+                    Err(message) => self.status = message,
+                }
+                continue;
+            }
+            if self.edit.is_some() {
+                self.edit_key(key);
+                continue;
+            }
+            match key.code {
+                KeyCode::Esc | KeyCode::Char('q') => return Err(cancelled()),
+                KeyCode::Tab => self.pane = self.next_pane(1),
+                KeyCode::BackTab => self.pane = self.next_pane(2),
+                _ => match self.pane {
+                    Pane::Metadata => self.metadata_key(key),
+                    Pane::Properties => self.properties_key(key),
+                    Pane::Preview => self.preview_key(key),
+                },
+            }
+        }
+    }
+
+    fn next_pane(&self, step: usize) -> Pane {
+        let panes = [Pane::Metadata, Pane::Properties, Pane::Preview];
+        let current = panes
+            .iter()
+            .position(|pane| *pane == self.pane)
+            .unwrap_or(0);
+        panes[(current + step) % panes.len()]
+    }
+
+    fn metadata_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Up => self.metadata_row = self.metadata_row.saturating_sub(1),
+            KeyCode::Down => self.metadata_row = (self.metadata_row + 1).min(METADATA.len() - 1),
+            KeyCode::Enter => {
+                self.edit = Some(Edit {
+                    buffer: self.metadata[self.metadata_row].clone(),
+                    new_row: false,
+                })
+            }
+            _ => {}
+        }
+    }
+
+    fn properties_key(&mut self, key: KeyEvent) {
+        let row = self.table.selected().unwrap_or(0);
+        let column = self.table.selected_column().unwrap_or(0);
+        let shift = key.modifiers.contains(KeyModifiers::SHIFT);
+        match key.code {
+            KeyCode::Up if shift && row > 0 => {
+                self.properties.swap(row, row - 1);
+                self.table.select(Some(row - 1));
+            }
+            KeyCode::Down if shift && row + 1 < self.properties.len() => {
+                self.properties.swap(row, row + 1);
+                self.table.select(Some(row + 1));
+            }
+            KeyCode::Up => self.table.select(Some(row.saturating_sub(1))),
+            KeyCode::Down => self
+                .table
+                .select(Some((row + 1).min(self.properties.len().saturating_sub(1)))),
+            KeyCode::Left => self.table.select_column(Some(column.saturating_sub(1))),
+            KeyCode::Right => self
+                .table
+                .select_column(Some((column + 1).min(COLUMNS.len() - 1))),
+            KeyCode::Char('a') => {
+                self.properties
+                    .push((String::new(), Field::new(Property::String)));
+                self.table.select(Some(self.properties.len() - 1));
+                self.table.select_column(Some(0));
+                self.edit = Some(Edit {
+                    buffer: String::new(),
+                    new_row: true,
+                });
+            }
+            KeyCode::Char('d') | KeyCode::Delete if row < self.properties.len() => {
+                self.properties.remove(row);
+                self.table
+                    .select(Some(row.min(self.properties.len().saturating_sub(1))));
+            }
+            KeyCode::Enter | KeyCode::Char(' ') if row < self.properties.len() => match column {
+                0 => {
+                    self.edit = Some(Edit {
+                        buffer: self.properties[row].0.clone(),
+                        new_row: false,
+                    })
+                }
+                1 => {
+                    let field = &mut self.properties[row].1;
+                    let options = Property::get_vec();
+                    let current = options
+                        .iter()
+                        .position(|option| *option == field.kind)
+                        .unwrap_or(0);
+                    field.kind = options[(current + 1) % options.len()];
+                }
+                2 => self.properties[row].1.list = !self.properties[row].1.list,
+                _ if self.properties[row].1.kind == Property::Enum => {
+                    self.edit = Some(Edit {
+                        buffer: self.properties[row].1.values.join(", "),
+                        new_row: false,
+                    })
+                }
+                _ if self.properties[row].1.kind == Property::Related => {
+                    self.edit = Some(Edit {
+                        buffer: relation_cell(&self.properties[row].1),
+                        new_row: false,
+                    })
+                }
+                _ => {
+                    self.status = "Only Enum values and Related relations can be edited".to_owned()
+                }
+            },
+            _ => {}
+        }
+    }
+
+    fn preview_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Left => {
+                self.preview = (self.preview + PREVIEWS.len() - 1) % PREVIEWS.len();
+                self.scroll = 0;
+            }
+            KeyCode::Right => {
+                self.preview = (self.preview + 1) % PREVIEWS.len();
+                self.scroll = 0;
+            }
+            KeyCode::Up => self.scroll = self.scroll.saturating_sub(1),
+            KeyCode::Down => self.scroll = self.scroll.saturating_add(1),
+            KeyCode::PageUp => self.scroll = self.scroll.saturating_sub(20),
+            KeyCode::PageDown => self.scroll = self.scroll.saturating_add(20),
+            _ => {}
+        }
+    }
+
+    fn edit_key(&mut self, key: KeyEvent) {
+        let Some(edit) = self.edit.as_mut() else {
+            return;
+        };
+        match key.code {
+            KeyCode::Char(_)
+                if key
+                    .modifiers
+                    .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) => {}
+            KeyCode::Char(c) => edit.buffer.push(c),
+            KeyCode::Backspace => {
+                edit.buffer.pop();
+            }
+            KeyCode::Esc => {
+                if edit.new_row {
+                    self.properties.pop();
+                    self.table
+                        .select(Some(self.properties.len().saturating_sub(1)));
+                }
+                self.edit = None;
+                self.status.clear();
+            }
+            KeyCode::Enter => match self.commit() {
+                Ok(()) => {
+                    self.edit = None;
+                    self.status.clear();
+                }
+                Err(message) => self.status = message,
+            },
+            _ => {}
+        }
+    }
+
+    /// Stores the edited value if it passes the same checks as the wizard.
+    fn commit(&mut self) -> Result<(), String> {
+        let buffer = self
+            .edit
+            .as_ref()
+            .map(|edit| edit.buffer.clone())
+            .unwrap_or_default();
+        if self.pane == Pane::Properties {
+            let row = self.table.selected().unwrap_or(0);
+            if self.table.selected_column() == Some(3) {
+                let field = &mut self.properties[row].1;
+                if field.kind == Property::Related {
+                    *field = parse_relation(&buffer, field)?;
+                } else {
+                    field.values = validate_enum_values(&buffer)?;
+                }
+                return Ok(());
+            }
+            let key = validate_property(&buffer)?;
+            if self
+                .properties
+                .iter()
+                .enumerate()
+                .any(|(index, (other, _))| index != row && *other == key)
+            {
+                return Err(format!("{} was already added", key));
+            }
+            self.properties[row].0 = key;
+            return Ok(());
+        }
+        let value = match self.metadata_row {
+            0 | 1 => validate_name(&buffer)?,
+            2 | 3 => validate_route(&buffer)?,
+            _ => buffer.trim().to_owned(),
+        };
+        self.metadata[self.metadata_row] = value;
+        self.fill_defaults();
+        Ok(())
+    }
+
+    /// Offers the wizard defaults for the values still empty.
+    fn fill_defaults(&mut self) {
+        let [section, plural, front_route, api_route, icon] = &mut self.metadata;
+        if plural.is_empty() && !section.is_empty() {
+            *plural = pluralize(
+                section,
+                self.project.language,
+                &self.project.irregular_plurals,
+            );
+        }
+        if front_route.is_empty() {
+            *front_route = plural.clone();
+        }
+        if api_route.is_empty() {
+            *api_route = front_route.clone();
+        }
+        if icon.is_empty() {
+            *icon = self.project.default_icon.clone();
+        }
+    }
+
+    fn config(&self) -> Config {
+        let [section, plural, front_route, api_route, icon] = self.metadata.clone();
+        Config::new(
+            section,
+            plural,
+            api_route,
+            front_route,
+            icon,
+            self.properties
+                .iter()
+                .filter(|(key, _)| !key.is_empty())
+                .map(|(key, field)| {
+                    let mut field = field.clone();
+                    field.retain_applicable();
+                    (key.clone(), field)
+                })
+                .collect(),
+            self.pages,
+        )
+    }
+
+    fn validated(&self) -> Result<Config, String> {
+        let config = self.config();
+        config.validate()?;
+        Ok(config)
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let [main, help] =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(2)]).areas(frame.area());
+        let [left, right] =
+            Layout::horizontal([Constraint::Percentage(45), Constraint::Percentage(55)])
+                .areas(main);
+        let [metadata, properties] = Layout::vertical([
+            Constraint::Length(METADATA.len() as u16 + 2),
+            Constraint::Min(0),
+        ])
+        .areas(left);
+        self.draw_metadata(frame, metadata);
+        self.draw_properties(frame, properties);
+        self.draw_preview(frame, right);
+        self.draw_help(frame, help);
+    }
+
+    fn block(&self, pane: Pane, title: &str) -> Block<'static> {
+        let style = if self.pane == pane {
+            Style::new().fg(Color::Cyan)
+        } else {
+            Style::new().fg(Color::DarkGray)
+        };
+        Block::bordered()
+            .title(format!(" {} ", title))
+            .border_style(style)
+    }
+
+    fn draw_metadata(&self, frame: &mut Frame, area: Rect) {
+        let lines: Vec<Line> = METADATA
+            .iter()
+            .zip(&self.metadata)
+            .enumerate()
+            .map(|(index, (label, value))| {
+                let selected = self.pane == Pane::Metadata && index == self.metadata_row;
+                let value = match &self.edit {
+                    Some(edit) if selected => format!("{}▏", edit.buffer),
+                    _ => value.clone(),
+                };
+                let style = if selected {
+                    Style::new().add_modifier(Modifier::REVERSED)
+                } else {
+                    Style::new()
+                };
+                Line::from(vec![
+                    Span::styled(format!("{:15}", label), Style::new().fg(Color::Cyan)),
+                    Span::styled(value, style),
+                ])
+            })
+            .collect();
+        frame.render_widget(
+            Paragraph::new(lines).block(self.block(Pane::Metadata, "Entity")),
+            area,
+        );
+    }
+
+    fn draw_properties(&mut self, frame: &mut Frame, area: Rect) {
+        let editing = match &self.edit {
+            Some(edit) if self.pane == Pane::Properties => Some(edit),
+            _ => None,
+        };
+        let selected = self.table.selected();
+        let column = self.table.selected_column();
+        let rows: Vec<Row> = self
+            .properties
+            .iter()
+            .enumerate()
+            .map(|(index, (key, field))| {
+                let mut cells = vec![
+                    key.clone(),
+                    field.kind.to_string(),
+                    if field.list { "yes" } else { "no" }.to_owned(),
+                    match field.kind {
+                        Property::Enum => field.values.join(", "),
+                        Property::Related => relation_cell(field),
+                        _ => String::new(),
+                    },
+                ];
+                if let (Some(edit), Some(column)) = (editing, column) {
+                    if Some(index) == selected {
+                        cells[column] = format!("{}▏", edit.buffer);
+                    }
+                }
+                Row::new(cells)
+            })
+            .collect();
+        let focused = self.pane == Pane::Properties;
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Min(12),
+                Constraint::Length(8),
+                Constraint::Length(6),
+                Constraint::Min(10),
+            ],
+        )
+        .header(Row::new(COLUMNS).style(Style::new().fg(Color::Cyan)))
+        .block(self.block(
+            Pane::Properties,
+            &format!("Properties ({})", self.properties.len()),
+        ))
+        .row_highlight_style(if focused {
+            Style::new().bg(Color::DarkGray)
+        } else {
+            Style::new()
+        })
+        .cell_highlight_style(if focused {
+            Style::new().add_modifier(Modifier::REVERSED)
+        } else {
+            Style::new()
+        });
+        frame.render_stateful_widget(table, area, &mut self.table);
+    }
+
+    fn draw_preview(&self, frame: &mut Frame, area: Rect) {
+        let block = self.block(Pane::Preview, "Preview");
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+        let [tabs, contents] =
+            Layout::vertical([Constraint::Length(1), Constraint::Min(0)]).areas(inner);
+        frame.render_widget(
+            Tabs::new(PREVIEWS)
+                .select(self.preview)
+                .highlight_style(Style::new().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+            tabs,
+        );
+        let text = preview(
+            &self.templates,
+            &self.config(),
+            self.project,
+            &self.models,
+            &self.services,
+            PREVIEWS[self.preview],
+        )
+        .unwrap_or_else(|err| err.to_string());
+        frame.render_widget(Paragraph::new(text).scroll((self.scroll, 0)), contents);
+    }
+
+    /// Whether the values cell of a `Related` property is selected.
+    fn relation_selected(&self) -> bool {
+        self.table.selected_column() == Some(3)
+            && self
+                .table
+                .selected()
+                .and_then(|row| self.properties.get(row))
+                .is_some_and(|(_, field)| field.kind == Property::Related)
+    }
+
+    fn draw_help(&self, frame: &mut Frame, area: Rect) {
+        let keys = match (self.pane, self.edit.is_some()) {
+            (Pane::Properties, true) if self.relation_selected() => {
+                "Target, Cardinality, display · Enter save · Esc discard"
+            }
+            (_, true) => "Enter save · Esc discard",
+            (Pane::Metadata, false) => "↑↓ move · Enter edit",
+            (Pane::Properties, false) if self.relation_selected() => {
+                "↑↓←→ move · Enter edit Target, Cardinality, display · a add · d delete"
+            }
+            (Pane::Properties, false) => {
+                "↑↓←→ move · Enter edit/cycle · a add · d delete · Shift+↑↓ reorder"
+            }
+            (Pane::Preview, false) => "←→ file · ↑↓ PgUp PgDn scroll",
+        };
+        let lines = vec![
+            Line::styled(self.status.clone(), Style::new().fg(Color::Red)),
+            Line::from(format!("{} · Tab pane · Ctrl+S generate · q quit", keys)),
+        ];
+        frame.render_widget(Paragraph::new(lines), area);
+    }
+}
+
+/// The values cell of a `Related` property, as `Target, Cardinality, display`.
+fn relation_cell(field: &Field) -> String {
+    format!(
+        "{}, {}, {}",
+        field.target.as_deref().unwrap_or_default(),
+        field.cardinality,
+        field.display
+    )
+}
+
+/// Reads a values cell written as `relation_cell` shows it, the parts left out keep their value
+/// and an empty target relates to the model named after the key.
+fn parse_relation(buffer: &str, field: &Field) -> Result<Field, String> {
+    let mut relation = field.clone();
+    let mut parts = buffer.split(',').map(str::trim);
+    relation.target = match parts.next().unwrap_or_default() {
+        "" => None,
+        target => Some(pascal_case(&validate_name(target)?)),
+    };
+    if let Some(cardinality) = parts.next().filter(|part| !part.is_empty()) {
+        relation.cardinality = cardinality.parse()?;
+    }
+    if let Some(display) = parts.next().filter(|part| !part.is_empty()) {
+        relation.display = validate_property(display)?;
+    }
+    if parts.next().is_some() {
+        return Err("expected Target, Cardinality, display".to_owned());
+    }
+    if relation.cardinality.is_many() {
+        relation.nullable = false;
+    }
+    validate_modifiers(&relation)?;
+    Ok(relation)
+}
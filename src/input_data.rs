@@ -1,27 +1,69 @@
 use console::Style;
-use std::io::stdin;
+use std::io::{stdin, Error, ErrorKind, IsTerminal};
 
-pub fn input_data(var: &mut String, label: &str, color: &Style, required: bool, def: Option<&str>) {
-    let defaulty = def.map_or_else(|| "", |d| d);
-    println!(
-        "Please input {} {}",
-        color.apply_to(label),
-        if defaulty.is_empty() {
-            "".to_owned()
-        } else {
-            format!("({})", defaulty)
-        }
-    );
+/// Checks a trimmed answer, returning the value to keep or the reason to ask again.
+pub type Validator<'a> = &'a dyn Fn(&str) -> Result<String, String>;
 
-    stdin().read_line(var).expect("Error while tryying to read");
-    if var.len() < 3 {
-        var.insert_str(0, defaulty);
+pub fn input_data(
+    var: &mut String,
+    label: &str,
+    color: &Style,
+    required: bool,
+    def: Option<&str>,
+    validate: Option<Validator>,
+) -> Result<(), Error> {
+    require_terminal()?;
+    let defaulty = def.unwrap_or_default();
+    loop {
+        println!(
+            "Please input {} {}",
+            color.apply_to(label),
+            if defaulty.is_empty() {
+                "".to_owned()
+            } else {
+                format!("({})", defaulty)
+            }
+        );
+        let mut line = String::new();
+        if stdin().read_line(&mut line)? == 0 {
+            return Err(cancelled());
+        }
+        let mut value = line.trim().to_owned();
+        if value.is_empty() {
+            value = defaulty.to_owned();
+        }
+        if value.is_empty() && required {
+            continue;
+        }
+        match validate.map(|validate| validate(&value)) {
+            Some(Err(message)) if !value.is_empty() || required => {
+                println!("{}", Style::new().red().apply_to(message));
+            }
+            Some(Ok(valid)) => {
+                *var = valid;
+                return Ok(());
+            }
+            _ => {
+                *var = value;
+                return Ok(());
+            }
+        }
     }
-    if var.len() < 3 && required {
-        input_data(var, label, color, required, def);
+}
+
+/// Prompts need someone typing, piped input would be read as answers to the wrong questions.
+pub fn require_terminal() -> Result<(), Error> {
+    if stdin().is_terminal() {
+        Ok(())
     } else {
-        let lowered = var.to_owned();
-        var.clear();
-        var.insert_str(0, lowered.as_str());
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "stdin is not a terminal, pass every value with flags or use generate --spec",
+        ))
     }
 }
+
+/// The user gave up answering: end of input, Ctrl-C or Esc.
+pub fn cancelled() -> Error {
+    Error::new(ErrorKind::Interrupted, "Cancelled, nothing was written")
+}
@@ -1,30 +1,224 @@
 use crate::create::{create, Config};
-use crate::input_data::input_data;
+use crate::designer::design;
+use crate::input_data::{cancelled, input_data};
+use crate::naming::pascal_case;
+use crate::plan::APPLYING;
+use crate::plural::pluralize;
+use crate::project::Project;
+use crate::resolve_conflicts::{conflicts_error, resolve_conflicts};
 use crate::set_properties::set_properties;
-use console::{Color, Emoji, Style};
-use models::Property;
-use std::collections::HashMap;
-use std::io::Error;
+use crate::spec::read_spec;
+use crate::validate::{validate_enum_values, validate_name, validate_property, validate_route};
+use clap::{Parser, Subcommand};
+use console::{Color, Emoji, Style, Term};
+use dialoguer::Confirm;
+use indexmap::IndexMap;
+use models::{Field, Property};
+use std::io::{stdin, Error, ErrorKind, IsTerminal};
+use std::path::PathBuf;
+use std::process::{exit, ExitCode};
+use std::sync::atomic::Ordering;
 mod create;
+mod designer;
 mod input_data;
 mod models;
+mod naming;
+mod plan;
+mod plural;
+mod project;
+mod resolve_conflicts;
 mod set_properties;
-mod utils;
+mod spec;
+mod templates;
+mod ts_patch;
+mod validate;
 
-type Properties = HashMap<String, Property>;
+type Properties = IndexMap<String, Field>;
 
-fn main() -> Result<(), Error> {
+#[derive(Parser)]
+#[command(version, about)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+    /// Print the files that would be created or patched without touching disk
+    #[arg(long, global = true)]
+    dry_run: bool,
+    /// Overwrite files that already exist
+    #[arg(long, global = true)]
+    force: bool,
+    /// Project of angular.json to generate into, when the workspace has several
+    #[arg(long, global = true)]
+    project: Option<String>,
+    /// Entity name in singular
+    #[arg(long, value_parser = validate_name)]
+    section: Option<String>,
+    /// Entity name in plural
+    #[arg(long, value_parser = validate_name)]
+    plural: Option<String>,
+    /// Route used by the Angular router
+    #[arg(long, value_parser = validate_route)]
+    front_route: Option<String>,
+    /// Route used by the API
+    #[arg(long, value_parser = validate_route)]
+    api_route: Option<String>,
+    /// Font Awesome icon for the nav entry
+    #[arg(long)]
+    icon: Option<String>,
+    /// Property as name:Type, name:Enum:value,value for enums or name:Related:Model, can be
+    /// repeated
+    #[arg(long = "prop", value_name = "NAME:TYPE", value_parser = parse_prop)]
+    props: Vec<(String, Field)>,
+    /// Also generate nuevo, :id and :id/editar pages next to the dialogs
+    #[arg(long)]
+    pages: bool,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Design the entity in a full-screen editor with a live preview of the generated files
+    Design,
+    /// Generate an entity from a TOML, YAML or JSON spec file without prompting
+    Generate {
+        #[arg(long)]
+        spec: PathBuf,
+    },
+}
+
+fn parse_prop(raw: &str) -> Result<(String, Field), String> {
+    let (name, kind) = raw
+        .split_once(':')
+        .ok_or_else(|| format!("expected NAME:TYPE, found {}", raw))?;
+    let (kind, values) = match kind.split_once(':') {
+        Some((kind, values)) => (kind, Some(values)),
+        None => (kind, None),
+    };
+    let mut field = Field::new(kind.parse()?);
+    match (field.kind, values) {
+        (Property::Enum, Some(values)) => field.values = validate_enum_values(values)?,
+        (Property::Enum, None) => return Err("expected NAME:Enum:VALUE,VALUE".to_owned()),
+        (Property::Related, Some(target)) => {
+            field.target = Some(pascal_case(&validate_name(target)?))
+        }
+        (_, Some(_)) => return Err(format!("{} does not take values", field.kind)),
+        (_, None) => {}
+    }
+    Ok((validate_property(name)?, field))
+}
+
+fn generate(
+    config: Config,
+    project: &Project,
+    cli: &Cli,
+    interactive: bool,
+    main_color: &Style,
+) -> Result<(), Error> {
+    let mut plan = create(config, project)?;
+    if cli.dry_run {
+        plan.print(main_color);
+        return Ok(());
+    }
+    let conflicts = plan.conflicts();
+    if !conflicts.is_empty() && !cli.force {
+        if !interactive {
+            return Err(conflicts_error(&conflicts));
+        }
+        resolve_conflicts(&mut plan, conflicts, main_color)?;
+    }
+    plan.apply()?;
+    for note in plan.notes() {
+        println!("{}", note);
+    }
+    println!();
+    println!(
+        "{} Creation was {} {}",
+        Emoji("✨", ":-)"),
+        &main_color.apply_to("successfull"),
+        Emoji("✨", ":-)")
+    );
+    Ok(())
+}
+
+/// Exit code of a cancelled run, the one shells use for SIGINT.
+const CANCELLED: u8 = 130;
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let _ = ctrlc::set_handler(|| {
+        if !APPLYING.load(Ordering::SeqCst) {
+            let _ = Term::stderr().show_cursor();
+            eprintln!("\n{}", cancelled());
+            exit(CANCELLED.into());
+        }
+    });
+    match run(cli) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) if err.kind() == ErrorKind::Interrupted => {
+            let _ = Term::stderr().show_cursor();
+            eprintln!("{}", cancelled());
+            ExitCode::from(CANCELLED)
+        }
+        Err(err) => {
+            eprintln!("{} {}", Style::new().red().apply_to("Error:"), err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(cli: Cli) -> Result<(), Error> {
+    let project = Project::discover(cli.project.as_deref())?;
     let main_color = Style::new().cyan();
     let magenta = Style::new().magenta().bg(Color::Black);
     let blue = Style::new().blue().bg(Color::Black);
     let green = Style::new().green().bg(Color::Black);
     let key_color = Style::new().on_cyan().bg(Color::Black);
     let value_color = Style::new().yellow().bg(Color::Black);
-    let mut section = String::new();
-    let mut section_plural = String::new();
-    let mut front_route = String::new();
-    let mut api_route = String::new();
-    let mut icon = String::new();
+    let mut section = cli.section.clone().unwrap_or_default();
+    let mut section_plural = cli.plural.clone().unwrap_or_default();
+    let mut front_route = cli.front_route.clone().unwrap_or_default();
+    let mut api_route = cli.api_route.clone().unwrap_or_default();
+    let mut icon = cli.icon.clone().unwrap_or_default();
+
+    let mut properties = Properties::new();
+    for (key, field) in &cli.props {
+        if properties.insert(key.clone(), field.clone()).is_some() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("{} was already added", key),
+            ));
+        }
+    }
+    match &cli.command {
+        Some(Command::Generate { spec }) => {
+            // The spec describes the whole entity, only --pages adds to it.
+            let entity_flags = [
+                ("--section", cli.section.is_some()),
+                ("--plural", cli.plural.is_some()),
+                ("--front-route", cli.front_route.is_some()),
+                ("--api-route", cli.api_route.is_some()),
+                ("--icon", cli.icon.is_some()),
+                ("--prop", !cli.props.is_empty()),
+            ];
+            if let Some((flag, _)) = entity_flags.iter().find(|(_, given)| *given) {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    format!("{} can not be used with generate, set it in the spec", flag),
+                ));
+            }
+            let mut config = read_spec(spec)?;
+            config.add_pages(cli.pages);
+            return generate(config, &project, &cli, false, &main_color);
+        }
+        Some(Command::Design) => {
+            let config = design(
+                &project,
+                [section, section_plural, front_route, api_route, icon],
+                properties,
+                cli.pages,
+            )?;
+            return generate(config, &project, &cli, true, &main_color);
+        }
+        None => {}
+    }
 
     println!(
         "{} This is {} creator {}",
@@ -32,46 +226,108 @@ fn main() -> Result<(), Error> {
         &main_color.apply_to("your"),
         Emoji("✨", ":-)")
     );
-    input_data(&mut section, "SECTION", &magenta, true, None);
-    section = section.trim().replace(' ', "_");
-    input_data(
-        &mut section_plural,
-        "SECTION PLURAL",
-        &blue,
-        true,
-        Some(format!("{}s", section).as_str()),
-    );
-    section_plural = section_plural.trim().replace(' ', "_");
-    input_data(
-        &mut front_route,
-        "FRONT ROUTE",
-        &green,
-        false,
-        Some(section_plural.as_str()),
-    );
-    front_route = front_route.trim().replace(' ', "_");
-    input_data(
-        &mut api_route,
-        "API ROUTE",
-        &green,
-        false,
-        Some(front_route.trim()),
-    );
-    api_route = api_route.trim().replace(' ', "_");
+    if section.is_empty() {
+        input_data(
+            &mut section,
+            "SECTION",
+            &magenta,
+            true,
+            None,
+            Some(&validate_name),
+        )?;
+    }
+    section = section.trim().to_owned();
+    if section_plural.is_empty() {
+        input_data(
+            &mut section_plural,
+            "SECTION PLURAL",
+            &blue,
+            true,
+            Some(pluralize(&section, project.language, &project.irregular_plurals).as_str()),
+            Some(&validate_name),
+        )?;
+    }
+    section_plural = section_plural.trim().to_owned();
+    if front_route.is_empty() {
+        input_data(
+            &mut front_route,
+            "FRONT ROUTE",
+            &green,
+            false,
+            Some(section_plural.as_str()),
+            Some(&validate_route),
+        )?;
+    }
+    front_route = front_route.trim().to_owned();
+    if api_route.is_empty() {
+        input_data(
+            &mut api_route,
+            "API ROUTE",
+            &green,
+            false,
+            Some(front_route.trim()),
+            Some(&validate_route),
+        )?;
+    }
+    api_route = api_route.trim().to_owned();
 
-    input_data(&mut icon, "ICON", &main_color, false, Some("fa-user"));
+    if icon.is_empty() {
+        input_data(
+            &mut icon,
+            "ICON",
+            &main_color,
+            false,
+            Some(project.default_icon.as_str()),
+            None,
+        )?;
+    }
     icon = icon.trim().replace(' ', "_");
 
-    let mut properties: Properties = HashMap::new();
-    let options = Property::get_vec();
+    let mut properties = properties;
+    if properties.is_empty() {
+        let options = Property::get_vec();
+        set_properties(
+            &mut properties,
+            options,
+            &project.models(),
+            &main_color,
+            &key_color,
+            &value_color,
+        )?;
+    }
 
-    set_properties(
-        &mut properties,
-        options,
-        &main_color,
-        &key_color,
-        &value_color,
-    )?;
+    let interactive = stdin().is_terminal();
+    let pages = cli.pages
+        || interactive
+            && Confirm::new()
+                .with_prompt("Also generate nuevo, :id and :id/editar pages?")
+                .default(false)
+                .interact_opt()?
+                .ok_or_else(cancelled)?;
+    let config = Config::new(
+        section,
+        section_plural,
+        api_route,
+        front_route,
+        icon,
+        properties,
+        pages,
+    );
+    config
+        .validate()
+        .map_err(|err| Error::new(ErrorKind::InvalidInput, err))?;
+    if interactive {
+        println!();
+        print!("{}", config.summary(&key_color));
+        if !Confirm::new()
+            .with_prompt("Generate it?")
+            .default(true)
+            .interact_opt()?
+            .unwrap_or(false)
+        {
+            return Err(cancelled());
+        }
+    }
 
     println!(
         "{} Your {} is being {} {}",
@@ -81,20 +337,5 @@ fn main() -> Result<(), Error> {
         Emoji("🙌", ":-)")
     );
 
-    create(Config::new(
-        section,
-        section_plural,
-        api_route,
-        front_route,
-        icon,
-        properties,
-    ))?;
-    println!();
-    println!(
-        "{} Creation was {} {}",
-        Emoji("✨", ":-)"),
-        &main_color.apply_to("successfull"),
-        Emoji("✨", ":-)")
-    );
-    Ok(())
+    generate(config, &project, &cli, interactive, &main_color)
 }
@@ -1,16 +1,35 @@
+use crate::validate::validate_default;
+use serde::de::value::MapAccessDeserializer;
+use serde::de::{Error, MapAccess, Visitor};
+use serde::{Deserialize, Deserializer};
 use std::fmt::Display;
-#[derive(Clone, Copy, Debug)]
+use std::str::FromStr;
+/// Read from specs as text, ignoring case like `--prop`.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+#[serde(try_from = "String")]
 pub enum Property {
     String,
     Number,
     Date,
     Related,
+    Boolean,
+    Enum,
+    Text,
+    Email,
+    Url,
+    Phone,
 }
-const PROPERTIES: [Property; 4] = [
+const PROPERTIES: [Property; 10] = [
     Property::String,
     Property::Number,
     Property::Date,
     Property::Related,
+    Property::Boolean,
+    Property::Enum,
+    Property::Text,
+    Property::Email,
+    Property::Url,
+    Property::Phone,
 ];
 impl Display for Property {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -19,10 +38,39 @@ impl Display for Property {
             Property::Number => write!(f, "Number"),
             Property::String => write!(f, "String"),
             Property::Related => write!(f, "Related"),
+            Property::Boolean => write!(f, "Boolean"),
+            Property::Enum => write!(f, "Enum"),
+            Property::Text => write!(f, "Text"),
+            Property::Email => write!(f, "Email"),
+            Property::Url => write!(f, "Url"),
+            Property::Phone => write!(f, "Phone"),
         }
     }
 }
 impl Property {
+    /// Kinds stored as a string and edited in a text input.
+    pub fn is_text(&self) -> bool {
+        matches!(
+            self,
+            Property::String | Property::Text | Property::Email | Property::Url | Property::Phone
+        )
+    }
+
+    /// Kinds with a minimum and maximum: the value of a `Number`, the length of a text.
+    pub fn has_limits(&self) -> bool {
+        *self == Property::Number || self.is_text()
+    }
+
+    /// Kinds that take a custom pattern, the other texts already check their own format.
+    pub fn has_pattern(&self) -> bool {
+        matches!(self, Property::String | Property::Text)
+    }
+
+    /// Kinds whose values can be compared as sent by the API to check that they are unique.
+    pub fn can_be_unique(&self) -> bool {
+        *self == Property::Number || self.is_text()
+    }
+
     pub fn get_vec() -> Vec<Property> {
         let mut v = Vec::new();
         for prop in PROPERTIES.iter() {
@@ -31,3 +79,264 @@ impl Property {
         v
     }
 }
+impl FromStr for Property {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        PROPERTIES
+            .iter()
+            .find(|prop| prop.to_string().eq_ignore_ascii_case(s.trim()))
+            .copied()
+            .ok_or_else(|| {
+                format!(
+                    "unknown property type {}, expected one of {}",
+                    s,
+                    Property::get_vec()
+                        .iter()
+                        .map(|prop| prop.to_string())
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                )
+            })
+    }
+}
+impl TryFrom<String> for Property {
+    type Error = String;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+/// How many entities a `Related` property points to.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq)]
+#[serde(try_from = "String")]
+pub enum Cardinality {
+    /// Stores the id of one related entity.
+    #[default]
+    BelongsTo,
+    /// The related entities keep the foreign key, they are listed but not edited here.
+    HasMany,
+    /// Linked through a pivot table, edited as a list of ids.
+    ManyToMany,
+}
+const CARDINALITIES: [Cardinality; 3] = [
+    Cardinality::BelongsTo,
+    Cardinality::HasMany,
+    Cardinality::ManyToMany,
+];
+impl Display for Cardinality {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Cardinality::BelongsTo => write!(f, "BelongsTo"),
+            Cardinality::HasMany => write!(f, "HasMany"),
+            Cardinality::ManyToMany => write!(f, "ManyToMany"),
+        }
+    }
+}
+impl Cardinality {
+    pub fn get_vec() -> Vec<Cardinality> {
+        CARDINALITIES.to_vec()
+    }
+
+    /// Points to a list of entities instead of a single one.
+    pub fn is_many(&self) -> bool {
+        *self != Cardinality::BelongsTo
+    }
+
+    /// Sent by the form of this entity, `HasMany` children are edited from their own.
+    pub fn is_edited(&self) -> bool {
+        *self != Cardinality::HasMany
+    }
+}
+impl FromStr for Cardinality {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        CARDINALITIES
+            .iter()
+            .find(|cardinality| cardinality.to_string().eq_ignore_ascii_case(s.trim()))
+            .copied()
+            .ok_or_else(|| {
+                format!(
+                    "unknown cardinality {}, expected one of {}",
+                    s,
+                    CARDINALITIES
+                        .iter()
+                        .map(|cardinality| cardinality.to_string())
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                )
+            })
+    }
+}
+impl TryFrom<String> for Cardinality {
+    type Error = String;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+#[derive(Clone, Debug)]
+pub struct Field {
+    pub kind: Property,
+    pub list: bool,
+    /// Allowed values of an `Enum` property.
+    pub values: Vec<String>,
+    /// Optional properties end in `?` in the model and the form accepts them empty.
+    pub required: bool,
+    /// Stored as `null` when empty, the model allows it and the form submits empty texts as `null`.
+    pub nullable: bool,
+    /// No other entity can have the same value, checked against the API before saving.
+    pub unique: bool,
+    /// Initial value of the form control, as written in the spec or the wizard.
+    pub default: Option<String>,
+    /// Smallest value of a `Number` or shortest text.
+    pub min: Option<f64>,
+    /// Largest value of a `Number` or longest text.
+    pub max: Option<f64>,
+    /// Regular expression the whole text has to match.
+    pub pattern: Option<String>,
+    /// Shown but disabled when editing an existing entity.
+    pub readonly: bool,
+    /// Model a `Related` property points to, by default the one named after its key.
+    pub target: Option<String>,
+    pub cardinality: Cardinality,
+    /// Property of the related model shown in selects and lists.
+    pub display: String,
+}
+impl Field {
+    pub fn new(kind: Property) -> Field {
+        Field {
+            kind,
+            list: true,
+            values: Vec::new(),
+            required: true,
+            nullable: false,
+            unique: false,
+            default: None,
+            min: None,
+            max: None,
+            pattern: None,
+            readonly: false,
+            target: None,
+            cardinality: Cardinality::BelongsTo,
+            display: default_display(),
+        }
+    }
+
+    /// Drops the values and modifiers the current kind can not use.
+    pub fn retain_applicable(&mut self) {
+        if self.kind != Property::Enum {
+            self.values.clear();
+        }
+        if !self.kind.has_limits() {
+            self.min = None;
+            self.max = None;
+        }
+        if !self.kind.has_pattern() {
+            self.pattern = None;
+        }
+        if !self.kind.can_be_unique() {
+            self.unique = false;
+        }
+        if self.kind != Property::Related {
+            self.target = None;
+            self.cardinality = Cardinality::BelongsTo;
+            self.display = default_display();
+        }
+        if let Some(default) = &self.default {
+            self.default = validate_default(self.kind, &self.values, default).ok();
+        }
+    }
+}
+/// A spec entry is either the bare type (`price = "Number"`) or a table with options.
+impl<'de> Deserialize<'de> for Field {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(FieldVisitor)
+    }
+}
+struct FieldVisitor;
+impl<'de> Visitor<'de> for FieldVisitor {
+    type Value = Field;
+    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "a property type or a table with its type and modifiers")
+    }
+    fn visit_str<E: Error>(self, value: &str) -> Result<Field, E> {
+        value.parse().map(Field::new).map_err(E::custom)
+    }
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Field, A::Error> {
+        FieldOptions::deserialize(MapAccessDeserializer::new(map)).map(Field::from)
+    }
+}
+/// The table form of a spec entry, unknown keys are rejected so typos do not go unnoticed.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FieldOptions {
+    #[serde(rename = "type")]
+    kind: Property,
+    #[serde(default = "default_true")]
+    list: bool,
+    #[serde(default)]
+    values: Vec<String>,
+    #[serde(default = "default_true")]
+    required: bool,
+    #[serde(default)]
+    nullable: bool,
+    #[serde(default)]
+    unique: bool,
+    #[serde(default)]
+    default: Option<Scalar>,
+    #[serde(default)]
+    min: Option<f64>,
+    #[serde(default)]
+    max: Option<f64>,
+    #[serde(default)]
+    pattern: Option<String>,
+    #[serde(default)]
+    readonly: bool,
+    #[serde(default)]
+    target: Option<String>,
+    #[serde(default)]
+    cardinality: Cardinality,
+    #[serde(default = "default_display")]
+    display: String,
+}
+/// A default value written as a bare boolean, number or string in the spec.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Scalar {
+    Boolean(bool),
+    Number(f64),
+    Text(String),
+}
+impl Display for Scalar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Scalar::Boolean(value) => write!(f, "{}", value),
+            Scalar::Number(value) => write!(f, "{}", value),
+            Scalar::Text(value) => write!(f, "{}", value),
+        }
+    }
+}
+fn default_true() -> bool {
+    true
+}
+fn default_display() -> String {
+    "name".to_owned()
+}
+impl From<FieldOptions> for Field {
+    fn from(options: FieldOptions) -> Self {
+        Field {
+            kind: options.kind,
+            list: options.list,
+            values: options.values,
+            required: options.required,
+            nullable: options.nullable,
+            unique: options.unique,
+            default: options.default.map(|default| default.to_string()),
+            min: options.min,
+            max: options.max,
+            pattern: options.pattern,
+            readonly: options.readonly,
+            target: options.target,
+            cardinality: options.cardinality,
+            display: options.display,
+        }
+    }
+}
@@ -0,0 +1,144 @@
+//! Case conversions for the names typed by the user. Words are split on anything that is not
+//! a letter or a digit and on camelCase boundaries, so `categoría producto`, `categoria-producto`
+//! and `CategoriaProducto` all derive the same identifiers.
+
+/// `CategoriaProducto`, for classes, interfaces and enum members.
+pub fn pascal_case(input: &str) -> String {
+    words(input, true)
+        .iter()
+        .map(|word| capitalize(word))
+        .collect()
+}
+
+/// `categoriaProducto`, for variables and class properties.
+pub fn camel_case(input: &str) -> String {
+    let pascal = pascal_case(input);
+    let mut chars = pascal.chars();
+    match chars.next() {
+        Some(first) => first.to_lowercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// `categoria-producto`, for file and folder names.
+pub fn kebab_case(input: &str) -> String {
+    words(input, true).join("-")
+}
+
+/// `categoria_producto`
+pub fn snake_case(input: &str) -> String {
+    words(input, true).join("_")
+}
+
+/// `CATEGORIA_PRODUCTO`
+pub fn screaming_case(input: &str) -> String {
+    snake_case(input).to_uppercase()
+}
+
+/// `categoria-producto/detalle`, kebab-case keeping the `/` between URL segments.
+pub fn url_path(input: &str) -> String {
+    input
+        .split('/')
+        .map(kebab_case)
+        .filter(|segment| !segment.is_empty())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// The input transliterated to ASCII with every run of other characters replaced by `_`,
+/// keeping its case so `fecha alta` becomes `fecha_alta` and `createdAt` is left alone.
+pub fn identifier(input: &str) -> String {
+    let mut identifier = String::new();
+    for c in input.trim().chars() {
+        match transliterate(c) {
+            Some(ascii) => identifier.push_str(&ascii),
+            None if !identifier.ends_with('_') => identifier.push('_'),
+            None => {}
+        }
+    }
+    identifier.trim_matches('_').to_owned()
+}
+
+/// `Categoría producto`, for text shown to the user.
+pub fn sentence_case(input: &str) -> String {
+    capitalize(&words(input, false).join(" "))
+}
+
+/// `categoría producto`
+pub fn lower_words(input: &str) -> String {
+    words(input, false).join(" ")
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Lowercase words of `input`, transliterated to ASCII when `ascii` is set.
+fn words(input: &str, ascii: bool) -> Vec<String> {
+    let chars: Vec<char> = input
+        .chars()
+        .flat_map(|c| match transliterate(c) {
+            Some(folded) if ascii => folded.chars().collect::<Vec<_>>(),
+            Some(_) => vec![c],
+            None => vec![' '],
+        })
+        .collect();
+    let mut words = Vec::new();
+    let mut word = String::new();
+    for (index, &c) in chars.iter().enumerate() {
+        if !c.is_alphanumeric() {
+            if !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+            continue;
+        }
+        let previous = index.checked_sub(1).map(|previous| chars[previous]);
+        let next = chars.get(index + 1);
+        // `fechaAlta` and `HTTPServer` split before the uppercase letter starting a word.
+        let boundary = c.is_uppercase()
+            && previous.is_some_and(|previous| {
+                previous.is_lowercase()
+                    || previous.is_ascii_digit()
+                    || (previous.is_uppercase() && next.is_some_and(|next| next.is_lowercase()))
+            });
+        if boundary && !word.is_empty() {
+            words.push(std::mem::take(&mut word));
+        }
+        word.extend(c.to_lowercase());
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+    words
+}
+
+/// ASCII spelling of a letter or digit, `None` for separators and unknown characters.
+fn transliterate(c: char) -> Option<String> {
+    if c.is_ascii_alphanumeric() {
+        return Some(c.to_string());
+    }
+    let lower = c.to_lowercase().next()?;
+    let ascii = match lower {
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' => "a",
+        'è' | 'é' | 'ê' | 'ë' | 'ē' => "e",
+        'ì' | 'í' | 'î' | 'ï' | 'ī' => "i",
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' => "o",
+        'ù' | 'ú' | 'û' | 'ü' | 'ū' => "u",
+        'ý' | 'ÿ' => "y",
+        'ñ' => "n",
+        'ç' => "c",
+        'ß' => "ss",
+        'æ' => "ae",
+        'œ' => "oe",
+        _ => return None,
+    };
+    Some(if c.is_uppercase() {
+        ascii.to_uppercase()
+    } else {
+        ascii.to_owned()
+    })
+}
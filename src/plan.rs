@@ -0,0 +1,262 @@
+use console::Style;
+use similar::TextDiff;
+use std::fs::{create_dir, read, read_to_string, remove_dir, remove_file, rename, write};
+use std::io::{Error, ErrorKind};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Set while a plan is being written, Ctrl-C is ignored meanwhile so it never stops halfway.
+pub static APPLYING: AtomicBool = AtomicBool::new(false);
+
+pub enum Change {
+    Create {
+        path: PathBuf,
+        contents: String,
+    },
+    Patch {
+        path: PathBuf,
+        original: String,
+        contents: String,
+    },
+}
+
+/// How to revert one step of an applied plan.
+enum Undo {
+    RemoveDir(PathBuf),
+    RemoveFile(PathBuf),
+    Restore(PathBuf, Vec<u8>),
+}
+
+/// Every file a generation creates or patches, kept in memory until applied.
+#[derive(Default)]
+pub struct Plan {
+    changes: Vec<Change>,
+    notes: Vec<String>,
+}
+
+impl Change {
+    fn path(&self) -> &PathBuf {
+        match self {
+            Change::Create { path, .. } | Change::Patch { path, .. } => path,
+        }
+    }
+
+    fn contents(&self) -> &str {
+        match self {
+            Change::Create { contents, .. } | Change::Patch { contents, .. } => contents,
+        }
+    }
+}
+
+impl Plan {
+    pub fn create(&mut self, path: impl Into<PathBuf>, contents: String) {
+        self.changes.push(Change::Create {
+            path: path.into(),
+            contents,
+        });
+    }
+
+    /// Records a patch, unless it leaves the file as it was.
+    pub fn patch(&mut self, path: impl Into<PathBuf>, original: String, contents: String) {
+        if original == contents {
+            return;
+        }
+        self.changes.push(Change::Patch {
+            path: path.into(),
+            original,
+            contents,
+        });
+    }
+
+    /// Records something the user should know about the generation, like a skipped entry.
+    pub fn note(&mut self, note: String) {
+        self.notes.push(note);
+    }
+
+    pub fn notes(&self) -> &[String] {
+        &self.notes
+    }
+
+    /// Paths of the files to create that already exist on disk.
+    pub fn conflicts(&self) -> Vec<PathBuf> {
+        self.changes
+            .iter()
+            .filter_map(|change| match change {
+                Change::Create { path, .. } if path.exists() => Some(path.to_owned()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn skip(&mut self, skipped: &Path) {
+        self.changes.retain(|change| match change {
+            Change::Create { path, .. } => path != skipped,
+            Change::Patch { .. } => true,
+        });
+    }
+
+    /// Prints the diff between an existing file and the contents that would replace it.
+    pub fn print_conflict(&self, conflict: &Path) -> Result<(), Error> {
+        for change in &self.changes {
+            if let Change::Create { path, contents } = change {
+                if path == conflict {
+                    let existing = read_to_string(path)?;
+                    print_diff(&path.display().to_string(), &existing, contents);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes every change, restoring all touched files and directories if any step fails.
+    pub fn apply(&self) -> Result<(), Error> {
+        APPLYING.store(true, Ordering::SeqCst);
+        let result = self.apply_all();
+        APPLYING.store(false, Ordering::SeqCst);
+        result
+    }
+
+    fn apply_all(&self) -> Result<(), Error> {
+        let mut undo = Vec::new();
+        if let Err(err) = self.apply_changes(&mut undo) {
+            let not_restored = rollback(undo);
+            let message = if not_restored.is_empty() {
+                format!("{}, every touched file was restored", err)
+            } else {
+                format!("{}, could not restore {}", err, not_restored.join(", "))
+            };
+            return Err(Error::new(err.kind(), message));
+        }
+        Ok(())
+    }
+
+    fn apply_changes(&self, undo: &mut Vec<Undo>) -> Result<(), Error> {
+        for change in &self.changes {
+            apply_change(change, undo).map_err(|err| {
+                Error::new(err.kind(), format!("{}: {}", change.path().display(), err))
+            })?;
+        }
+        Ok(())
+    }
+
+    pub fn print(&self, title: &Style) {
+        println!("{}", title.apply_to("Files to create:"));
+        for change in &self.changes {
+            if let Change::Create { path, .. } = change {
+                if path.exists() {
+                    println!("  {} (overwrites existing file)", path.display());
+                } else {
+                    println!("  {}", path.display());
+                }
+            }
+        }
+        println!("{}", title.apply_to("Files to patch:"));
+        for change in &self.changes {
+            if let Change::Patch { path, .. } = change {
+                println!("  {}", path.display());
+            }
+        }
+        for note in &self.notes {
+            println!("{}", note);
+        }
+        for change in &self.changes {
+            println!();
+            match change {
+                Change::Create { path, contents } => {
+                    println!("{}", title.apply_to(format!("+++ {}", path.display())));
+                    print!("{}", contents);
+                }
+                Change::Patch {
+                    path,
+                    original,
+                    contents,
+                } => print_diff(&path.display().to_string(), original, contents),
+            }
+        }
+    }
+}
+
+pub fn print_diff(path: &str, original: &str, contents: &str) {
+    let removed = Style::new().red();
+    let added = Style::new().green();
+    let diff = TextDiff::from_lines(original, contents);
+    for line in diff.unified_diff().header(path, path).to_string().lines() {
+        if line.starts_with('-') {
+            println!("{}", removed.apply_to(line));
+        } else if line.starts_with('+') {
+            println!("{}", added.apply_to(line));
+        } else {
+            println!("{}", line);
+        }
+    }
+}
+
+fn apply_change(change: &Change, undo: &mut Vec<Undo>) -> Result<(), Error> {
+    let path = change.path();
+    if let Some(parent) = path.parent() {
+        create_dirs(parent, undo)?;
+    }
+    match read(path) {
+        Ok(previous) => undo.push(Undo::Restore(path.to_owned(), previous)),
+        Err(err) if err.kind() == ErrorKind::NotFound => {
+            undo.push(Undo::RemoveFile(path.to_owned()))
+        }
+        Err(err) => return Err(err),
+    }
+    write_atomic(path, change.contents())
+}
+
+/// Creates the missing directories of `dir` one by one so each can be removed on rollback.
+fn create_dirs(dir: &Path, undo: &mut Vec<Undo>) -> Result<(), Error> {
+    let mut missing: Vec<&Path> = dir
+        .ancestors()
+        .take_while(|ancestor| !ancestor.as_os_str().is_empty() && !ancestor.exists())
+        .collect();
+    missing.reverse();
+    for ancestor in missing {
+        create_dir(ancestor)?;
+        undo.push(Undo::RemoveDir(ancestor.to_owned()));
+    }
+    Ok(())
+}
+
+/// Writes to a sibling temporary file and renames it, so a file is never left half written.
+fn write_atomic(path: &Path, contents: &str) -> Result<(), Error> {
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let staged = path.with_file_name(format!(".{}.core-script", file_name));
+    write(&staged, contents)?;
+    rename(&staged, path).inspect_err(|_| {
+        let _ = remove_file(&staged);
+    })
+}
+
+/// Reverts the applied steps in reverse order, returning the paths that could not be restored.
+fn rollback(undo: Vec<Undo>) -> Vec<String> {
+    let mut not_restored = Vec::new();
+    for step in undo.into_iter().rev() {
+        let (path, result) = match step {
+            Undo::RemoveDir(path) => {
+                let result = remove_dir(&path);
+                (path, result)
+            }
+            Undo::RemoveFile(path) => {
+                let result = match remove_file(&path) {
+                    Err(err) if err.kind() == ErrorKind::NotFound => Ok(()),
+                    result => result,
+                };
+                (path, result)
+            }
+            Undo::Restore(path, previous) => {
+                let result = write(&path, previous);
+                (path, result)
+            }
+        };
+        if result.is_err() {
+            not_restored.push(path.display().to_string());
+        }
+    }
+    not_restored
+}
@@ -0,0 +1,263 @@
+//! Plural forms used as the default for `section_plural`. The rules cover regular nouns,
+//! anything else belongs in the irregulars table of the project config.
+
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+#[derive(Clone, Copy, Deserialize)]
+pub enum Language {
+    #[serde(rename = "es")]
+    Spanish,
+    #[serde(rename = "en")]
+    English,
+}
+
+const SPANISH_IRREGULARS: &[(&str, &str)] = &[
+    ("carácter", "caracteres"),
+    ("espécimen", "especímenes"),
+    ("régimen", "regímenes"),
+];
+
+const ENGLISH_IRREGULARS: &[(&str, &str)] = &[
+    ("child", "children"),
+    ("echo", "echoes"),
+    ("foot", "feet"),
+    ("goose", "geese"),
+    ("half", "halves"),
+    ("hero", "heroes"),
+    ("knife", "knives"),
+    ("leaf", "leaves"),
+    ("life", "lives"),
+    ("man", "men"),
+    ("mouse", "mice"),
+    ("ox", "oxen"),
+    ("person", "people"),
+    ("potato", "potatoes"),
+    ("shelf", "shelves"),
+    ("thief", "thieves"),
+    ("tomato", "tomatoes"),
+    ("tooth", "teeth"),
+    ("wife", "wives"),
+    ("wolf", "wolves"),
+    ("woman", "women"),
+];
+
+const ENGLISH_UNCOUNTABLES: &[&str] = &[
+    "deer",
+    "equipment",
+    "feedback",
+    "fish",
+    "information",
+    "money",
+    "news",
+    "rice",
+    "series",
+    "sheep",
+    "software",
+    "species",
+];
+
+/// Plural of `input`. Compound names only change their head word, the first one in Spanish
+/// (`categoría producto` → `categorías producto`) and the last one in English.
+pub fn pluralize(input: &str, language: Language, irregulars: &BTreeMap<String, String>) -> String {
+    if let Some(plural) = irregular(input, language, irregulars) {
+        return plural;
+    }
+    let head = match language {
+        Language::Spanish => input
+            .find(char::is_alphabetic)
+            .map(|start| (start, word_end(input, start))),
+        Language::English => input
+            .char_indices()
+            .rev()
+            .find(|(_, c)| c.is_alphabetic())
+            .map(|(index, c)| (word_start(input, index), index + c.len_utf8())),
+    };
+    let Some((start, end)) = head else {
+        return input.to_owned();
+    };
+    let word = &input[start..end];
+    let plural = irregular(word, language, irregulars).unwrap_or_else(|| {
+        let lower = word.to_lowercase();
+        restore_case(
+            word,
+            &match language {
+                Language::Spanish => spanish(&lower),
+                Language::English => english(&lower),
+            },
+        )
+    });
+    format!("{}{}{}", &input[..start], plural, &input[end..])
+}
+
+fn word_end(input: &str, start: usize) -> usize {
+    input[start..]
+        .find(|c: char| !c.is_alphabetic())
+        .map_or(input.len(), |offset| start + offset)
+}
+
+fn word_start(input: &str, index: usize) -> usize {
+    input[..index]
+        .char_indices()
+        .rev()
+        .find(|(_, c)| !c.is_alphabetic())
+        .map_or(0, |(separator, c)| separator + c.len_utf8())
+}
+
+fn irregular(
+    word: &str,
+    language: Language,
+    irregulars: &BTreeMap<String, String>,
+) -> Option<String> {
+    let lower = word.to_lowercase();
+    let defaults = match language {
+        Language::Spanish => SPANISH_IRREGULARS,
+        Language::English => ENGLISH_IRREGULARS,
+    };
+    irregulars
+        .iter()
+        .find(|(singular, _)| singular.to_lowercase() == lower)
+        .map(|(_, plural)| plural.as_str())
+        .or_else(|| {
+            defaults
+                .iter()
+                .find(|(singular, _)| *singular == lower)
+                .map(|(_, plural)| *plural)
+        })
+        .map(|plural| restore_case(word, plural))
+}
+
+/// Keeps the letters `plural` shares with the lowercase `original` as they were typed, so
+/// `OrderItem` becomes `OrderItems`, and uppercases the rest when `original` is all caps.
+fn restore_case(original: &str, plural: &str) -> String {
+    let shared = original
+        .chars()
+        .zip(plural.chars())
+        .take_while(|(typed, lower)| typed.to_lowercase().eq(lower.to_lowercase()))
+        .count();
+    let rest: String = plural.chars().skip(shared).collect();
+    let upper = original.chars().count() > 1 && !original.chars().any(char::is_lowercase);
+    let mut restored: String = original.chars().take(shared).collect();
+    restored.push_str(&if upper { rest.to_uppercase() } else { rest });
+    restored
+}
+
+fn spanish(word: &str) -> String {
+    let Some(last) = word.chars().last() else {
+        return String::new();
+    };
+    match last {
+        'a' | 'e' | 'i' | 'o' | 'u' | 'á' | 'é' | 'ó' => format!("{}s", word),
+        'í' | 'ú' => format!("{}es", word),
+        'z' => format!("{}ces", &word[..word.len() - 1]),
+        // Unstressed final syllables are invariable: `lunes`, `crisis`, `tórax`.
+        's' | 'x' if vowel_groups(word) > 1 && !has_accent(last_syllable(word)) => word.to_owned(),
+        'n' | 's' if has_accent(last_syllable(word)) => format!("{}es", strip_accents(word)),
+        // Paroxytone words ending in `en` become proparoxytone: `joven` → `jóvenes`.
+        'n' if word.ends_with("en") && vowel_groups(word) > 1 && !has_accent(word) => {
+            format!("{}es", accent_penultimate(word))
+        }
+        'l' | 'n' | 'r' | 'd' | 'j' | 'y' | 's' | 'x' => format!("{}es", word),
+        _ => format!("{}s", word),
+    }
+}
+
+fn english(word: &str) -> String {
+    if ENGLISH_UNCOUNTABLES.contains(&word) {
+        return word.to_owned();
+    }
+    let before_last = word.chars().rev().nth(1);
+    if word.ends_with('y') && before_last.is_some_and(|c| !"aeiou".contains(c)) {
+        format!("{}ies", &word[..word.len() - 1])
+    } else if word.ends_with("is") && word.len() > 3 {
+        format!("{}es", &word[..word.len() - 2])
+    } else if ["s", "x", "z", "ch", "sh"]
+        .iter()
+        .any(|suffix| word.ends_with(suffix))
+    {
+        format!("{}es", word)
+    } else {
+        format!("{}s", word)
+    }
+}
+
+fn is_vowel(c: char) -> bool {
+    "aeiouáéíóúü".contains(c)
+}
+
+fn has_accent(text: &str) -> bool {
+    text.contains(['á', 'é', 'í', 'ó', 'ú'])
+}
+
+/// Drops the written accent of words that stop being oxytone, keeping the ones marking a
+/// hiatus like `país` → `países`.
+fn strip_accents(word: &str) -> String {
+    let mut stripped = String::with_capacity(word.len());
+    let mut previous = ' ';
+    for c in word.chars() {
+        stripped.push(match c {
+            'á' => 'a',
+            'é' => 'e',
+            'ó' => 'o',
+            'í' if !is_vowel(previous) => 'i',
+            'ú' if !is_vowel(previous) => 'u',
+            c => c,
+        });
+        previous = c;
+    }
+    stripped
+}
+
+fn vowel_groups(word: &str) -> usize {
+    vowel_group_starts(word).len()
+}
+
+/// Byte offsets where each run of vowels starts.
+fn vowel_group_starts(word: &str) -> Vec<usize> {
+    let mut starts = Vec::new();
+    let mut previous_vowel = false;
+    for (index, c) in word.char_indices() {
+        if is_vowel(c) && !previous_vowel {
+            starts.push(index);
+        }
+        previous_vowel = is_vowel(c);
+    }
+    starts
+}
+
+fn last_syllable(word: &str) -> &str {
+    vowel_group_starts(word)
+        .last()
+        .map_or(word, |start| &word[*start..])
+}
+
+/// Puts a written accent on the vowel group before the last one, on its strong vowel if any.
+fn accent_penultimate(word: &str) -> String {
+    let starts = vowel_group_starts(word);
+    let start = starts[starts.len() - 2];
+    let group: Vec<(usize, char)> = word[start..]
+        .char_indices()
+        .take_while(|(_, c)| is_vowel(*c))
+        .map(|(offset, c)| (start + offset, c))
+        .collect();
+    let (index, vowel) = group
+        .iter()
+        .find(|(_, c)| "aeo".contains(*c))
+        .or(group.last())
+        .copied()
+        .expect("vowel groups are never empty");
+    let accented = match vowel {
+        'a' => 'á',
+        'e' => 'é',
+        'i' => 'í',
+        'o' => 'ó',
+        'u' => 'ú',
+        other => other,
+    };
+    format!(
+        "{}{}{}",
+        &word[..index],
+        accented,
+        &word[index + vowel.len_utf8()..]
+    )
+}
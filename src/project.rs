@@ -0,0 +1,331 @@
+use crate::plural::Language;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::env::current_dir;
+use std::fs::{read_dir, read_to_string};
+use std::io::{Error, ErrorKind};
+use std::path::{Component, Path, PathBuf};
+
+const CONFIG_FILE: &str = ".core-script.toml";
+const WORKSPACE_FILE: &str = "angular.json";
+
+/// Layout and conventions of the Angular project, read from `.core-script.toml`.
+/// Generated paths are relative to the `sourceRoot` of the selected project in
+/// `angular.json`, `templates` is relative to the workspace.
+#[derive(Deserialize)]
+#[serde(default)]
+pub struct Project {
+    #[serde(skip)]
+    pub workspace: PathBuf,
+    #[serde(skip)]
+    pub source_root: PathBuf,
+    pub views: PathBuf,
+    pub services: PathBuf,
+    pub models: PathBuf,
+    pub nav: PathBuf,
+    pub routes: PathBuf,
+    pub route_enums: PathBuf,
+    pub templates: PathBuf,
+    pub nav_url_suffix: String,
+    pub default_icon: String,
+    pub language: Language,
+    pub irregular_plurals: BTreeMap<String, String>,
+}
+
+impl Default for Project {
+    fn default() -> Self {
+        Project {
+            workspace: PathBuf::from("."),
+            source_root: PathBuf::from("src"),
+            views: PathBuf::from("app/views"),
+            services: PathBuf::from("app/services/http"),
+            models: PathBuf::from("app/shared/models"),
+            nav: PathBuf::from("app/_nav.ts"),
+            routes: PathBuf::from("app/_routes.ts"),
+            route_enums: PathBuf::from("app/constants/routes.ts"),
+            templates: PathBuf::from(".core-script/templates"),
+            nav_url_suffix: "/listar".to_owned(),
+            default_icon: "fa-user".to_owned(),
+            language: Language::Spanish,
+            irregular_plurals: BTreeMap::new(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Workspace {
+    #[serde(default)]
+    default_project: Option<String>,
+    #[serde(default)]
+    projects: BTreeMap<String, WorkspaceProject>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct WorkspaceProject {
+    #[serde(default)]
+    root: String,
+    source_root: Option<String>,
+}
+
+impl Project {
+    /// Locates `angular.json` walking up from the current directory and reads the first
+    /// `.core-script.toml` found on the way, falling back to the default layout.
+    /// `name` selects the workspace project, required when it has several.
+    pub fn discover(name: Option<&str>) -> Result<Project, Error> {
+        let cwd = current_dir()?;
+        let root = cwd
+            .ancestors()
+            .find(|dir| dir.join(WORKSPACE_FILE).is_file())
+            .ok_or_else(|| {
+                Error::new(
+                    ErrorKind::NotFound,
+                    format!(
+                        "Could not find {} in {} or any parent directory, run core-script inside an Angular workspace",
+                        WORKSPACE_FILE,
+                        cwd.display()
+                    ),
+                )
+            })?;
+        let mut project = match cwd
+            .ancestors()
+            .take_while(|dir| dir.starts_with(root))
+            .map(|dir| dir.join(CONFIG_FILE))
+            .find(|path| path.is_file())
+        {
+            Some(path) => {
+                toml::from_str(&read_to_string(&path)?).map_err(|err| invalid(&path, err))?
+            }
+            None => Project::default(),
+        };
+        project.workspace = if root == cwd {
+            PathBuf::from(".")
+        } else {
+            root.to_owned()
+        };
+        let relative_cwd = cwd.strip_prefix(root).unwrap_or(Path::new(""));
+        project.source_root =
+            project
+                .workspace
+                .join(source_root(&root.join(WORKSPACE_FILE), relative_cwd, name)?);
+        Ok(project)
+    }
+
+    /// Path of a generated file, relative to the source root.
+    pub fn path(&self, relative: &Path) -> PathBuf {
+        self.source_root.join(relative)
+    }
+
+    pub fn templates(&self) -> PathBuf {
+        self.workspace.join(&self.templates)
+    }
+
+    /// Entities declared by the `*.model.ts` files of the models folder, sorted by name.
+    pub fn models(&self) -> Vec<ModelFile> {
+        let mut models: Vec<ModelFile> = ts_files(&self.source_root, &self.models, ".model.ts")
+            .into_iter()
+            .filter_map(|(path, source)| {
+                let (name, fields) = parse_interface(&source)?;
+                let file = path.strip_prefix(&self.models).ok()?.to_string_lossy();
+                Some(ModelFile {
+                    name,
+                    file: file.trim_end_matches(".model.ts").replace('\\', "/"),
+                    fields,
+                })
+            })
+            .collect();
+        models.sort_by(|a, b| a.name.cmp(&b.name));
+        models
+    }
+
+    /// Services of the services folder with a `nurture…()` method.
+    pub fn services(&self) -> Vec<ServiceFile> {
+        ts_files(&self.source_root, &self.services, ".service.ts")
+            .into_iter()
+            .filter_map(|(path, source)| {
+                let (model, nurture) = parse_service(&source)?;
+                Some(ServiceFile {
+                    model,
+                    path,
+                    nurture,
+                })
+            })
+            .collect()
+    }
+}
+
+/// An entity already declared in the models folder.
+pub struct ModelFile {
+    pub name: String,
+    /// Path from the models folder, without `.model.ts`.
+    pub file: String,
+    pub fields: Vec<String>,
+}
+
+/// A `{model}Service` whose `nurture…()` method loads and caches every active entity.
+pub struct ServiceFile {
+    pub model: String,
+    /// Relative to the source root.
+    pub path: PathBuf,
+    pub nurture: String,
+}
+
+/// Path relative to the source root and content of the files under `dir` ending in `suffix`.
+/// Missing or unreadable entries are skipped, discovery only offers suggestions.
+fn ts_files(source_root: &Path, dir: &Path, suffix: &str) -> Vec<(PathBuf, String)> {
+    let mut files = Vec::new();
+    let Ok(entries) = read_dir(source_root.join(dir)) else {
+        return files;
+    };
+    for entry in entries.flatten() {
+        let path = dir.join(entry.file_name());
+        if entry.file_type().is_ok_and(|kind| kind.is_dir()) {
+            files.extend(ts_files(source_root, &path, suffix));
+        } else if entry.file_name().to_string_lossy().ends_with(suffix) {
+            if let Ok(source) = read_to_string(source_root.join(&path)) {
+                files.push((path, source));
+            }
+        }
+    }
+    files
+}
+
+fn is_identifier(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '$'
+}
+
+/// Name and property keys of the first exported interface.
+fn parse_interface(source: &str) -> Option<(String, Vec<String>)> {
+    let mut lines = source
+        .lines()
+        .map(str::trim)
+        .skip_while(|line| !line.starts_with("export interface "));
+    let name: String = lines
+        .next()?
+        .trim_start_matches("export interface ")
+        .chars()
+        .take_while(|c| is_identifier(*c))
+        .collect();
+    let fields = lines
+        .take_while(|line| !line.starts_with('}'))
+        .filter_map(|line| line.split_once(':'))
+        .map(|(key, _)| key.trim().trim_end_matches('?').trim_end().to_owned())
+        .filter(|key| !key.is_empty() && key.chars().all(is_identifier))
+        .collect();
+    (!name.is_empty()).then_some((name, fields))
+}
+
+/// Model and `nurture…` method of a `{model}Service` class.
+fn parse_service(source: &str) -> Option<(String, String)> {
+    let class: String = source
+        .split("export class ")
+        .nth(1)?
+        .chars()
+        .take_while(|c| is_identifier(*c))
+        .collect();
+    let model = class.strip_suffix("Service")?.to_owned();
+    let nurture = source.match_indices("nurture").find_map(|(index, _)| {
+        let method: String = source[index..]
+            .chars()
+            .take_while(|c| is_identifier(*c))
+            .collect();
+        source[index + method.len()..]
+            .starts_with('(')
+            .then_some(method)
+    })?;
+    (!model.is_empty()).then_some((model, nurture))
+}
+
+/// `sourceRoot` of the project called `name`, or else the project containing `cwd`, the
+/// default project or the only project, relative to the workspace.
+fn source_root(path: &Path, cwd: &Path, name: Option<&str>) -> Result<PathBuf, Error> {
+    let workspace: Workspace =
+        serde_json::from_str(&read_to_string(path)?).map_err(|err| invalid(path, err))?;
+    let names = || {
+        workspace
+            .projects
+            .keys()
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    let containing = || {
+        workspace
+            .projects
+            .values()
+            .filter(|project| !project.root.is_empty() && cwd.starts_with(&project.root))
+            .max_by_key(|project| project.root.len())
+    };
+    let single = || match workspace.projects.len() {
+        1 => workspace.projects.values().next(),
+        _ => None,
+    };
+    let project = match name {
+        Some(name) => workspace.projects.get(name).ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "There is no project {} in {}, available projects: {}",
+                    name,
+                    path.display(),
+                    names()
+                ),
+            )
+        })?,
+        None => containing()
+            .or_else(|| {
+                workspace
+                    .default_project
+                    .as_ref()
+                    .and_then(|name| workspace.projects.get(name))
+            })
+            .or_else(single)
+            .ok_or_else(|| {
+                Error::new(
+                    ErrorKind::InvalidInput,
+                    format!(
+                        "{} has several projects, choose one with --project: {}",
+                        path.display(),
+                        names()
+                    ),
+                )
+            })?,
+    };
+    Ok(match &project.source_root {
+        Some(source_root) => PathBuf::from(source_root),
+        None => Path::new(&project.root).join("src"),
+    })
+}
+
+fn invalid(path: &Path, err: impl std::fmt::Display) -> Error {
+    Error::new(
+        ErrorKind::InvalidData,
+        format!("Invalid {}: {}", path.display(), err),
+    )
+}
+
+/// Relative import specifier from the directory `from` to `to`, both relative to the root.
+pub fn relative_import(from: &Path, to: &Path) -> String {
+    let from: Vec<Component> = from.components().filter(is_normal).collect();
+    let to: Vec<Component> = to.components().filter(is_normal).collect();
+    let common = from
+        .iter()
+        .zip(to.iter())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let mut parts: Vec<String> = vec!["..".to_owned(); from.len() - common];
+    if parts.is_empty() {
+        parts.push(".".to_owned());
+    }
+    parts.extend(
+        to[common..]
+            .iter()
+            .map(|part| part.as_os_str().to_string_lossy().into_owned()),
+    );
+    parts.join("/")
+}
+
+fn is_normal(component: &Component) -> bool {
+    matches!(component, Component::Normal(_))
+}
@@ -0,0 +1,52 @@
+use console::{Style, Term};
+use dialoguer::{theme::ColorfulTheme, Select};
+use std::io::{Error, ErrorKind};
+use std::path::PathBuf;
+
+use crate::input_data::cancelled;
+use crate::plan::Plan;
+
+const CHOICES: [&str; 3] = ["Skip", "Overwrite", "Show diff"];
+
+pub fn conflicts_error(conflicts: &[PathBuf]) -> Error {
+    Error::new(
+        ErrorKind::AlreadyExists,
+        format!(
+            "These files already exist, use --force to overwrite them:\n{}",
+            conflicts
+                .iter()
+                .map(|path| format!("  {}", path.display()))
+                .collect::<Vec<String>>()
+                .join("\n")
+        ),
+    )
+}
+
+pub fn resolve_conflicts(
+    plan: &mut Plan,
+    conflicts: Vec<PathBuf>,
+    color: &Style,
+) -> Result<(), Error> {
+    for conflict in conflicts {
+        loop {
+            println!(
+                "{} already exists",
+                color.apply_to(conflict.display().to_string())
+            );
+            let selected = Select::with_theme(&ColorfulTheme::default())
+                .items(&CHOICES)
+                .default(0)
+                .interact_on_opt(&Term::stderr())?
+                .ok_or_else(cancelled)?;
+            match selected {
+                0 => {
+                    plan.skip(&conflict);
+                    break;
+                }
+                1 => break,
+                _ => plan.print_conflict(&conflict)?,
+            }
+        }
+    }
+    Ok(())
+}
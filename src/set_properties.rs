@@ -1,44 +1,395 @@
 use console::{Style, Term};
-use dialoguer::{theme::ColorfulTheme, Confirm, Select};
+use dialoguer::{theme::ColorfulTheme, Select, Sort};
 use std::io::Error;
 
-use crate::{input_data::input_data, models::Property, Properties};
+use crate::{
+    input_data::{cancelled, input_data, require_terminal, Validator},
+    models::{Cardinality, Field, Property},
+    naming::pascal_case,
+    project::ModelFile,
+    validate::{
+        validate_default, validate_enum_values, validate_limit, validate_modifiers, validate_name,
+        validate_property,
+    },
+    Properties,
+};
+
+const ACTIONS: [&str; 7] = [
+    "Add property",
+    "Rename property",
+    "Change type",
+    "Edit modifiers",
+    "Reorder properties",
+    "Delete property",
+    "Done",
+];
 
 pub fn set_properties(
     properties: &mut Properties,
     options: Vec<Property>,
+    models: &[ModelFile],
     main_color: &Style,
     color_key: &Style,
     color_value: &Style,
 ) -> Result<(), Error> {
-    if let Some(want_properties) = Confirm::new()
-        .with_prompt(format!(
-            "Want to add {} {}",
-            if !properties.is_empty() {
-                "more"
-            } else {
-                "some"
-            },
-            &main_color.apply_to("properties")
-        ))
-        .interact_opt()?
-    {
-        if want_properties {
-            let mut key = String::new();
-            input_data(&mut key, "PROPERTY NAME", color_key, true, None);
-            println!("Choice {}", color_value.apply_to("PROPERTY VALUE"));
-            let selected = Select::with_theme(&ColorfulTheme::default())
-                .items(&options)
-                .default(0)
-                .interact_on_opt(&Term::stderr())?
-                .unwrap();
-            let selected_parsed = options.get(selected).unwrap();
-            println!("{}", color_value.apply_to(selected_parsed));
-            properties.insert(key.trim().replace(' ', "_"), selected_parsed.to_owned());
-            set_properties(properties, options, main_color, color_key, color_value)?;
+    require_terminal()?;
+    loop {
+        if properties.is_empty() {
+            println!("No {} yet", main_color.apply_to("properties"));
+        } else {
+            println!("{}", main_color.apply_to("Properties"));
+            print!("{}", properties_table(properties));
+        }
+        // Editing actions only make sense once there is something to edit.
+        let actions: Vec<&str> = if properties.is_empty() {
+            vec![ACTIONS[0], ACTIONS[6]]
         } else {
-            return Ok(());
+            ACTIONS.to_vec()
+        };
+        let action = select(&actions, 0)?;
+        match actions[action] {
+            "Add property" => {
+                let key = ask_key(properties, None, color_key)?;
+                let mut field = Field::new(ask_kind(&options, None, color_value)?);
+                if field.kind == Property::Enum {
+                    field.values = ask_values(&[], color_value)?;
+                }
+                if field.kind == Property::Related {
+                    ask_relation(&mut field, models, color_value)?;
+                }
+                properties.insert(key, field);
+            }
+            "Rename property" => {
+                let index = pick(properties, "Property to rename")?;
+                let (old, _) = properties.get_index(index).expect("picked from the list");
+                let old = old.to_owned();
+                let key = ask_key(properties, Some(&old), color_key)?;
+                let field = properties.shift_remove(&old).expect("picked from the list");
+                properties.shift_insert(index, key, field);
+            }
+            "Change type" => {
+                let index = pick(properties, "Property to change")?;
+                let (_, field) = properties
+                    .get_index_mut(index)
+                    .expect("picked from the list");
+                field.kind = ask_kind(&options, Some(field.kind), color_value)?;
+                if field.kind == Property::Enum {
+                    field.values = ask_values(&field.values, color_value)?;
+                }
+                if field.kind == Property::Related {
+                    ask_relation(field, models, color_value)?;
+                }
+                field.retain_applicable();
+            }
+            "Edit modifiers" => {
+                let index = pick(properties, "Property to edit")?;
+                let (_, field) = properties
+                    .get_index_mut(index)
+                    .expect("picked from the list");
+                ask_modifiers(field, color_value)?;
+            }
+            "Reorder properties" => {
+                let keys: Vec<&String> = properties.keys().collect();
+                let order = Sort::with_theme(&ColorfulTheme::default())
+                    .with_prompt("Move with space and the arrows, enter to finish")
+                    .items(&keys)
+                    .interact_on_opt(&Term::stderr())?
+                    .ok_or_else(cancelled)?;
+                let mut reordered = Properties::new();
+                for index in order {
+                    let (key, field) = properties.get_index(index).expect("sorted from the list");
+                    reordered.insert(key.to_owned(), field.clone());
+                }
+                *properties = reordered;
+            }
+            "Delete property" => {
+                let index = pick(properties, "Property to delete")?;
+                properties.shift_remove_index(index);
+            }
+            _ => return Ok(()),
+        }
+    }
+}
+
+/// One line per property with its type, whether the list page shows it and its modifiers.
+pub fn properties_table(properties: &Properties) -> String {
+    let width = properties.keys().map(|key| key.len()).max().unwrap_or(0);
+    properties
+        .iter()
+        .enumerate()
+        .map(|(index, (key, field))| {
+            format!(
+                "  {}. {:width$}  {:8} {}{}{}\n",
+                index + 1,
+                key,
+                field.kind.to_string(),
+                if field.list { "listed" } else { "" },
+                match &field.target {
+                    Some(target) => format!(
+                        " ({} {}, shows {})",
+                        field.cardinality, target, field.display
+                    ),
+                    None if field.values.is_empty() => String::new(),
+                    None => format!(" ({})", field.values.join(", ")),
+                },
+                modifiers(field),
+                width = width
+            )
+        })
+        .collect()
+}
+
+fn modifiers(field: &Field) -> String {
+    let mut modifiers = Vec::new();
+    if !field.required {
+        modifiers.push("optional".to_owned());
+    }
+    if field.nullable {
+        modifiers.push("nullable".to_owned());
+    }
+    if field.unique {
+        modifiers.push("unique".to_owned());
+    }
+    if let Some(default) = &field.default {
+        modifiers.push(format!("default {}", default));
+    }
+    if let Some(min) = field.min {
+        modifiers.push(format!("min {}", min));
+    }
+    if let Some(max) = field.max {
+        modifiers.push(format!("max {}", max));
+    }
+    if let Some(pattern) = &field.pattern {
+        modifiers.push(format!("pattern {}", pattern));
+    }
+    if field.readonly {
+        modifiers.push("read-only on edit".to_owned());
+    }
+    if modifiers.is_empty() {
+        String::new()
+    } else {
+        format!(" [{}]", modifiers.join(", "))
+    }
+}
+
+fn select<T: ToString>(items: &[T], default: usize) -> Result<usize, Error> {
+    Select::with_theme(&ColorfulTheme::default())
+        .items(items)
+        .default(default)
+        .interact_on_opt(&Term::stderr())?
+        .ok_or_else(cancelled)
+}
+
+fn pick(properties: &Properties, prompt: &str) -> Result<usize, Error> {
+    println!("{}", prompt);
+    let keys: Vec<&String> = properties.keys().collect();
+    select(&keys, 0)
+}
+
+/// Asks for a property key not used by any other property than `current`.
+fn ask_key(
+    properties: &Properties,
+    current: Option<&str>,
+    color_key: &Style,
+) -> Result<String, Error> {
+    let unique = |value: &str| {
+        let key = validate_property(value)?;
+        if properties.contains_key(&key) && Some(key.as_str()) != current {
+            return Err(format!("{} was already added", key));
+        }
+        Ok(key)
+    };
+    let mut key = String::new();
+    input_data(
+        &mut key,
+        "PROPERTY NAME",
+        color_key,
+        true,
+        current,
+        Some(&unique),
+    )?;
+    Ok(key)
+}
+
+fn ask_values(current: &[String], color_value: &Style) -> Result<Vec<String>, Error> {
+    let mut values = String::new();
+    input_data(
+        &mut values,
+        "ENUM VALUES separated by commas",
+        color_value,
+        true,
+        Some(&current.join(", ")),
+        Some(&|value: &str| validate_enum_values(value).map(|values| values.join(","))),
+    )?;
+    Ok(values.split(',').map(str::to_owned).collect())
+}
+
+/// Asks for every modifier the kind of `field` takes, again until they agree with each other.
+fn ask_modifiers(field: &mut Field, color_value: &Style) -> Result<(), Error> {
+    loop {
+        println!("Is it {}", color_value.apply_to("REQUIRED"));
+        field.required = select(&["Required", "Optional"], usize::from(!field.required))? == 0;
+        let (kind, values) = (field.kind, field.values.clone());
+        if !(kind == Property::Related && field.cardinality.is_many()) {
+            println!("Can it be {}", color_value.apply_to("NULL"));
+            field.nullable = select(&["Not null", "Nullable"], usize::from(field.nullable))? == 1;
+        }
+        if kind.can_be_unique() {
+            println!("Has it to be {}", color_value.apply_to("UNIQUE"));
+            field.unique = select(&["Repeatable", "Unique"], usize::from(field.unique))? == 1;
+        }
+        field.default = ask_optional(
+            "DEFAULT VALUE",
+            field.default.as_deref(),
+            &|value: &str| validate_default(kind, &values, value),
+            color_value,
+        )?;
+        if kind.has_limits() {
+            let unit = if kind == Property::Number {
+                "VALUE"
+            } else {
+                "LENGTH"
+            };
+            let mut limits = [field.min, field.max];
+            for (limit, bound) in limits.iter_mut().zip(["MIN", "MAX"]) {
+                *limit = ask_optional(
+                    &format!("{} {}", bound, unit),
+                    limit.map(|limit| limit.to_string()).as_deref(),
+                    &validate_limit,
+                    color_value,
+                )?
+                .map(|limit| limit.parse().expect("validated as a number"));
+            }
+            [field.min, field.max] = limits;
+        }
+        if kind.has_pattern() {
+            field.pattern = ask_optional(
+                "PATTERN",
+                field.pattern.as_deref(),
+                &|value: &str| Ok(value.to_owned()),
+                color_value,
+            )?;
+        }
+        println!("Can it change {}", color_value.apply_to("WHEN EDITING"));
+        field.readonly = select(
+            &["Editable", "Read-only when editing"],
+            usize::from(field.readonly),
+        )? == 1;
+        match validate_modifiers(field) {
+            Ok(()) => return Ok(()),
+            Err(message) => println!("{}", Style::new().red().apply_to(message)),
         }
     }
+}
+
+/// Asks for a value that can be left out, `-` clears the current one.
+fn ask_optional(
+    label: &str,
+    current: Option<&str>,
+    validate: Validator,
+    color_value: &Style,
+) -> Result<Option<String>, Error> {
+    let mut value = String::new();
+    input_data(
+        &mut value,
+        &format!("{}, - for none", label),
+        color_value,
+        false,
+        current,
+        Some(&|value: &str| {
+            if value == "-" {
+                Ok(String::new())
+            } else {
+                validate(value)
+            }
+        }),
+    )?;
+    Ok(Some(value).filter(|value| !value.is_empty()))
+}
+
+/// Asks for the model a `Related` property points to, how many of them and which of their
+/// properties to show, offering the models already in the project.
+fn ask_relation(field: &mut Field, models: &[ModelFile], color_value: &Style) -> Result<(), Error> {
+    println!("Choice {}", color_value.apply_to("RELATED MODEL"));
+    let mut names: Vec<&str> = models.iter().map(|model| model.name.as_str()).collect();
+    names.push("Other model");
+    let current = field
+        .target
+        .as_deref()
+        .and_then(|target| names.iter().position(|name| *name == target))
+        .unwrap_or(0);
+    let chosen = models.get(select(&names, current)?);
+    let target = match chosen {
+        Some(model) => model.name.clone(),
+        None => {
+            let mut target = String::new();
+            input_data(
+                &mut target,
+                "RELATED MODEL NAME",
+                color_value,
+                true,
+                field.target.as_deref(),
+                Some(&|value: &str| validate_name(value).map(|name| pascal_case(&name))),
+            )?;
+            target
+        }
+    };
+    println!("{}", color_value.apply_to(&target));
+    field.target = Some(target);
+
+    println!("Choice {}", color_value.apply_to("CARDINALITY"));
+    let cardinalities = Cardinality::get_vec();
+    let current = cardinalities
+        .iter()
+        .position(|cardinality| *cardinality == field.cardinality)
+        .unwrap_or(0);
+    field.cardinality = cardinalities[select(&cardinalities, current)?];
+    if field.cardinality.is_many() {
+        field.nullable = false;
+    }
+
+    // Ids, foreign keys and timestamps make poor labels for a select.
+    let shown: Vec<&String> = chosen
+        .map(|model| {
+            model
+                .fields
+                .iter()
+                .filter(|key| validate_property(key).is_ok() && !key.ends_with("_id"))
+                .collect()
+        })
+        .unwrap_or_default();
+    if shown.is_empty() {
+        let mut display = String::new();
+        input_data(
+            &mut display,
+            "DISPLAY PROPERTY",
+            color_value,
+            true,
+            Some(&field.display),
+            Some(&validate_property),
+        )?;
+        field.display = display;
+    } else {
+        println!("Choice {}", color_value.apply_to("DISPLAY PROPERTY"));
+        let current = shown
+            .iter()
+            .position(|key| **key == field.display)
+            .unwrap_or(0);
+        field.display = shown[select(&shown, current)?].to_owned();
+    }
     Ok(())
 }
+
+fn ask_kind(
+    options: &[Property],
+    current: Option<Property>,
+    color_value: &Style,
+) -> Result<Property, Error> {
+    println!("Choice {}", color_value.apply_to("PROPERTY VALUE"));
+    let default = current
+        .and_then(|current| options.iter().position(|option| *option == current))
+        .unwrap_or(0);
+    let kind = options[select(options, default)?];
+    println!("{}", color_value.apply_to(kind));
+    Ok(kind)
+}
@@ -0,0 +1,39 @@
+use crate::create::Config;
+use std::fs::read_to_string;
+use std::io::{Error, ErrorKind};
+use std::path::Path;
+
+pub fn read_spec(path: &Path) -> Result<Config, Error> {
+    let content = read_to_string(path).map_err(|err| {
+        Error::new(
+            err.kind(),
+            format!("Could not read spec {}: {}", path.display(), err),
+        )
+    })?;
+    let extension = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .unwrap_or_default()
+        .to_lowercase();
+    let config: Config = match extension.as_str() {
+        "toml" => toml::from_str(&content).map_err(|e| invalid_spec(path, e)),
+        "yaml" | "yml" => serde_yaml::from_str(&content).map_err(|e| invalid_spec(path, e)),
+        "json" => serde_json::from_str(&content).map_err(|e| invalid_spec(path, e)),
+        _ => Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!(
+                "Unsupported spec format for {}, expected .toml, .yaml, .yml or .json",
+                path.display()
+            ),
+        )),
+    }?;
+    config.validate().map_err(|e| invalid_spec(path, e))?;
+    Ok(config)
+}
+
+fn invalid_spec(path: &Path, err: impl std::fmt::Display) -> Error {
+    Error::new(
+        ErrorKind::InvalidData,
+        format!("Invalid spec {}: {}", path.display(), err),
+    )
+}
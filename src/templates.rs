@@ -0,0 +1,141 @@
+use crate::naming::{camel_case, kebab_case, pascal_case, screaming_case, snake_case};
+use minijinja::syntax::SyntaxConfig;
+use minijinja::{AutoEscape, Environment, Value};
+use std::fs::read_to_string;
+use std::io::{Error, ErrorKind};
+use std::path::PathBuf;
+
+/// Templates shipped with the binary, looked up by file name.
+const DEFAULTS: &[(&str, &str)] = &[
+    ("model.ts", include_str!("../templates/model.ts")),
+    (
+        "form.component.html",
+        include_str!("../templates/form.component.html"),
+    ),
+    (
+        "form.component.ts",
+        include_str!("../templates/form.component.ts"),
+    ),
+    (
+        "form.fields.html",
+        include_str!("../templates/form.fields.html"),
+    ),
+    (
+        "form-page.component.html",
+        include_str!("../templates/form-page.component.html"),
+    ),
+    (
+        "form-page.component.ts",
+        include_str!("../templates/form-page.component.ts"),
+    ),
+    (
+        "list.component.html",
+        include_str!("../templates/list.component.html"),
+    ),
+    (
+        "list.component.ts",
+        include_str!("../templates/list.component.ts"),
+    ),
+    (
+        "show.component.html",
+        include_str!("../templates/show.component.html"),
+    ),
+    (
+        "show.component.ts",
+        include_str!("../templates/show.component.ts"),
+    ),
+    (
+        "show-page.component.html",
+        include_str!("../templates/show-page.component.html"),
+    ),
+    (
+        "show-page.component.ts",
+        include_str!("../templates/show-page.component.ts"),
+    ),
+    (
+        "modal.component.html",
+        include_str!("../templates/modal.component.html"),
+    ),
+    (
+        "modal.component.ts",
+        include_str!("../templates/modal.component.ts"),
+    ),
+    (
+        "routing.module.ts",
+        include_str!("../templates/routing.module.ts"),
+    ),
+    ("module.ts", include_str!("../templates/module.ts")),
+    ("resolver.ts", include_str!("../templates/resolver.ts")),
+    ("service.ts", include_str!("../templates/service.ts")),
+    (
+        "form.interface.ts",
+        include_str!("../templates/form.interface.ts"),
+    ),
+    (
+        "form.template.ts",
+        include_str!("../templates/form.template.ts"),
+    ),
+    (
+        "list.temp.functions.ts",
+        include_str!("../templates/list.temp.functions.ts"),
+    ),
+    (
+        "route.entry.ts",
+        include_str!("../templates/route.entry.ts"),
+    ),
+    ("nav.entry.ts", include_str!("../templates/nav.entry.ts")),
+];
+
+pub struct Templates {
+    env: Environment<'static>,
+}
+
+impl Templates {
+    /// Shipped templates, each one replaced by a file with the same name in `overrides`.
+    pub fn new(overrides: PathBuf) -> Templates {
+        let mut env = Environment::new();
+        env.set_syntax(
+            SyntaxConfig::builder()
+                .trim_blocks(true)
+                .lstrip_blocks(true)
+                .keep_trailing_newline(true)
+                .build()
+                .expect("default delimiters are valid"),
+        );
+        env.set_auto_escape_callback(|_| AutoEscape::None);
+        env.add_filter("pascal_case", |value: &str| pascal_case(value));
+        env.add_filter("camel_case", |value: &str| camel_case(value));
+        env.add_filter("kebab_case", |value: &str| kebab_case(value));
+        env.add_filter("snake_case", |value: &str| snake_case(value));
+        env.add_filter("screaming_case", |value: &str| screaming_case(value));
+        env.set_loader(move |name| {
+            let path = overrides.join(name);
+            if path.is_file() {
+                return read_to_string(&path).map(Some).map_err(|err| {
+                    minijinja::Error::new(
+                        minijinja::ErrorKind::InvalidOperation,
+                        format!("could not read {}", path.display()),
+                    )
+                    .with_source(err)
+                });
+            }
+            Ok(DEFAULTS
+                .iter()
+                .find(|(default, _)| *default == name)
+                .map(|(_, source)| source.to_string()))
+        });
+        Templates { env }
+    }
+
+    pub fn render(&self, name: &str, context: &Value) -> Result<String, Error> {
+        self.env
+            .get_template(name)
+            .and_then(|template| template.render(context))
+            .map_err(|err| {
+                Error::new(
+                    ErrorKind::InvalidData,
+                    format!("Could not render template {}: {:#}", name, err),
+                )
+            })
+    }
+}
@@ -0,0 +1,464 @@
+//! Minimal TypeScript scanner used to patch `_nav.ts`, `_routes.ts` and `constants/routes.ts`.
+//! It only understands enough of the language (strings, template literals, comments and
+//! brackets) to find the enum body or exported array to extend.
+
+#[derive(Debug, PartialEq)]
+enum TokenKind {
+    Ident,
+    Punct(char),
+    Str,
+}
+
+struct Token {
+    kind: TokenKind,
+    start: usize,
+    end: usize,
+}
+
+/// Sets each `(member, value)` of `enum {name}` to the string `value`, updating members that
+/// already exist in place and appending the missing ones at the end of the body.
+/// Returns the patched source and the members that were updated.
+pub fn upsert_enum_members(
+    src: &str,
+    name: &str,
+    members: &[(&str, &str)],
+) -> Result<(String, Vec<String>), String> {
+    let tokens = tokenize(src);
+    let (open, close) =
+        find_enum(src, &tokens, name).ok_or_else(|| format!("could not find enum {}", name))?;
+    let mut missing = String::new();
+    let mut replacements = Vec::new();
+    let mut updated = Vec::new();
+    for (member, value) in members {
+        let literal = format!("\"{}\"", value);
+        match find_member_value(src, &tokens[open + 1..close], member) {
+            Some((start, end)) if string_value(&src[start..end]) == Some(value) => {}
+            Some((start, end)) => {
+                updated.push(format!(
+                    "{}.{} from {} to {}",
+                    name,
+                    member,
+                    &src[start..end],
+                    literal
+                ));
+                replacements.push((start, end, literal));
+            }
+            None => missing.push_str(&format!("  {} = {},\n", member, literal)),
+        }
+    }
+    let mut patched = if missing.is_empty() {
+        src.to_owned()
+    } else {
+        insert_before_close(src, &tokens, open, close, &missing)
+    };
+    // The insertion only touches text after the last member, so earlier offsets stay valid.
+    for (start, end, literal) in replacements.into_iter().rev() {
+        patched.replace_range(start..end, &literal);
+    }
+    Ok((patched, updated))
+}
+
+/// Byte range of the value of `member = value` among the top level tokens of an enum body,
+/// up to the comma ending the member.
+fn find_member_value(src: &str, body: &[Token], member: &str) -> Option<(usize, usize)> {
+    let mut depth = 0;
+    for (index, token) in body.iter().enumerate() {
+        match token.kind {
+            TokenKind::Punct('{') | TokenKind::Punct('[') | TokenKind::Punct('(') => depth += 1,
+            TokenKind::Punct('}') | TokenKind::Punct(']') | TokenKind::Punct(')') => depth -= 1,
+            TokenKind::Ident
+                if depth == 0
+                    && token.text(src) == member
+                    && body.get(index + 1).map(|next| &next.kind)
+                        == Some(&TokenKind::Punct('=')) =>
+            {
+                let first = body.get(index + 2)?;
+                let mut end = first.end;
+                let mut nested = 0;
+                for token in &body[index + 2..] {
+                    match token.kind {
+                        TokenKind::Punct('{') | TokenKind::Punct('[') | TokenKind::Punct('(') => {
+                            nested += 1
+                        }
+                        TokenKind::Punct('}') | TokenKind::Punct(']') | TokenKind::Punct(')') => {
+                            nested -= 1
+                        }
+                        TokenKind::Punct(',') if nested == 0 => break,
+                        _ => {}
+                    }
+                    end = token.end;
+                }
+                return Some((first.start, end));
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Contents of a string or template literal without interpolations, `None` for any other value.
+fn string_value(text: &str) -> Option<&str> {
+    let quote = text
+        .chars()
+        .next()
+        .filter(|c| matches!(c, '"' | '\'' | '`'))?;
+    let inner = text.get(1..text.len() - 1)?;
+    (text.len() >= 2 && text.ends_with(quote) && !(quote == '`' && inner.contains("${")))
+        .then_some(inner)
+}
+
+/// Whether the first exported array literal already references `{object}.{member}`.
+pub fn array_references(src: &str, object: &str, member: &str) -> Result<bool, String> {
+    let tokens = tokenize(src);
+    let (open, close) = find_exported_array(src, &tokens).ok_or_else(array_not_found)?;
+    Ok(tokens[open..close].windows(3).any(|window| {
+        window[0].text(src) == object
+            && window[1].kind == TokenKind::Punct('.')
+            && window[2].text(src) == member
+    }))
+}
+
+/// Appends `entry` at the end of the first exported array literal.
+pub fn insert_array_entry(src: &str, entry: &str) -> Result<String, String> {
+    let tokens = tokenize(src);
+    let (open, close) = find_exported_array(src, &tokens).ok_or_else(array_not_found)?;
+    Ok(insert_before_close(src, &tokens, open, close, entry))
+}
+
+fn array_not_found() -> String {
+    "could not find an exported array (export const … = [ … ])".to_owned()
+}
+
+fn tokenize(src: &str) -> Vec<Token> {
+    let bytes = src.as_bytes();
+    let mut tokens = Vec::new();
+    // Brace depth of every `${` opened inside a template literal.
+    let mut templates: Vec<usize> = Vec::new();
+    let mut depth = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        let c = bytes[i];
+        match c {
+            b'/' if bytes.get(i + 1) == Some(&b'/') => {
+                while i < bytes.len() && bytes[i] != b'\n' {
+                    i += 1;
+                }
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                i += 2;
+                while i < bytes.len() && !(bytes[i] == b'*' && bytes.get(i + 1) == Some(&b'/')) {
+                    i += 1;
+                }
+                i += 2;
+            }
+            b'"' | b'\'' => {
+                let start = i;
+                i += 1;
+                while i < bytes.len() && bytes[i] != c && bytes[i] != b'\n' {
+                    if bytes[i] == b'\\' {
+                        i += 1;
+                    }
+                    i += 1;
+                }
+                i += 1;
+                tokens.push(Token {
+                    kind: TokenKind::Str,
+                    start,
+                    end: i.min(bytes.len()),
+                });
+            }
+            b'`' => {
+                i = template(bytes, i, &mut tokens, &mut templates, depth);
+            }
+            b'}' if templates.last() == Some(&depth) => {
+                templates.pop();
+                i = template(bytes, i, &mut tokens, &mut templates, depth);
+            }
+            c if c.is_ascii_alphanumeric() || c == b'_' || c == b'$' => {
+                let start = i;
+                while i < bytes.len()
+                    && (bytes[i].is_ascii_alphanumeric() || bytes[i] == b'_' || bytes[i] == b'$')
+                {
+                    i += 1;
+                }
+                tokens.push(Token {
+                    kind: TokenKind::Ident,
+                    start,
+                    end: i,
+                });
+            }
+            c if c.is_ascii_whitespace() || !c.is_ascii() => i += 1,
+            c => {
+                match c {
+                    b'{' | b'[' | b'(' => depth += 1,
+                    b'}' | b']' | b')' => depth = depth.saturating_sub(1),
+                    _ => {}
+                }
+                tokens.push(Token {
+                    kind: TokenKind::Punct(c as char),
+                    start: i,
+                    end: i + 1,
+                });
+                i += 1;
+            }
+        }
+    }
+    tokens
+}
+
+/// Reads the text of a template literal from `start`, its opening backtick or the `}` closing
+/// an interpolation, up to its closing backtick or the next `${`, as a single `Str` token.
+fn template(
+    bytes: &[u8],
+    start: usize,
+    tokens: &mut Vec<Token>,
+    templates: &mut Vec<usize>,
+    depth: usize,
+) -> usize {
+    let mut i = start + 1;
+    while i < bytes.len() {
+        let end = match bytes[i] {
+            b'\\' => {
+                i += 2;
+                continue;
+            }
+            b'`' => i + 1,
+            b'$' if bytes.get(i + 1) == Some(&b'{') => {
+                templates.push(depth);
+                i + 2
+            }
+            _ => {
+                i += 1;
+                continue;
+            }
+        };
+        tokens.push(Token {
+            kind: TokenKind::Str,
+            start,
+            end,
+        });
+        return end;
+    }
+    tokens.push(Token {
+        kind: TokenKind::Str,
+        start,
+        end: bytes.len(),
+    });
+    bytes.len()
+}
+
+impl Token {
+    fn text<'a>(&self, src: &'a str) -> &'a str {
+        &src[self.start..self.end]
+    }
+}
+
+/// Index of the token closing the bracket opened at `open`.
+fn matching(tokens: &[Token], open: usize) -> Option<usize> {
+    let mut depth = 0;
+    for (index, token) in tokens.iter().enumerate().skip(open) {
+        match token.kind {
+            TokenKind::Punct('{') | TokenKind::Punct('[') | TokenKind::Punct('(') => depth += 1,
+            TokenKind::Punct('}') | TokenKind::Punct(']') | TokenKind::Punct(')') => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(index);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Token indexes of the braces delimiting the body of `enum {name}`.
+fn find_enum(src: &str, tokens: &[Token], name: &str) -> Option<(usize, usize)> {
+    let position = tokens.windows(3).position(|window| {
+        window[0].text(src) == "enum"
+            && window[1].text(src) == name
+            && window[2].kind == TokenKind::Punct('{')
+    })?;
+    let open = position + 2;
+    Some((open, matching(tokens, open)?))
+}
+
+/// Token indexes of the brackets delimiting the first `export const … = [ … ]`.
+fn find_exported_array(src: &str, tokens: &[Token]) -> Option<(usize, usize)> {
+    let mut index = 0;
+    while index + 1 < tokens.len() {
+        if tokens[index].text(src) == "export" && tokens[index + 1].text(src) == "const" {
+            let assign = tokens[index..]
+                .iter()
+                .position(|token| token.kind == TokenKind::Punct('='))?
+                + index;
+            if tokens.get(assign + 1)?.kind == TokenKind::Punct('[') {
+                let open = assign + 1;
+                return Some((open, matching(tokens, open)?));
+            }
+            index = assign;
+        }
+        index += 1;
+    }
+    None
+}
+
+/// Inserts `entries` right before the token at `close`, after the last entry of the body
+/// opened at `open`, adding the separating comma and matching indentation and line endings.
+fn insert_before_close(
+    src: &str,
+    tokens: &[Token],
+    open: usize,
+    close: usize,
+    entries: &str,
+) -> String {
+    let newline = if src.contains("\r\n") { "\r\n" } else { "\n" };
+    let close_at = tokens[close].start;
+    let line_start = src[..close_at].rfind('\n').map_or(0, |index| index + 1);
+    let before_close = &src[line_start..close_at];
+    let indent: String = before_close
+        .chars()
+        .take_while(|c| *c == ' ' || *c == '\t')
+        .collect();
+    let mut inserted = String::new();
+    for line in entries.lines() {
+        if line.is_empty() {
+            inserted.push_str(newline);
+        } else {
+            inserted.push_str(&format!("{}{}{}", indent, line, newline));
+        }
+    }
+    // On the line of the last entry, the entries go on their own lines and the whitespace
+    // that separated the close from the last entry is dropped.
+    let (insert_at, resume_at, prefix) = if before_close.trim().is_empty() {
+        (line_start, line_start, "")
+    } else {
+        (src[..close_at].trim_end().len(), close_at, newline)
+    };
+    let last = &tokens[close - 1];
+    let needs_comma = close - 1 > open && last.kind != TokenKind::Punct(',');
+    let mut patched = String::with_capacity(src.len() + inserted.len() + 1);
+    if needs_comma {
+        patched.push_str(&src[..last.end]);
+        patched.push(',');
+        patched.push_str(&src[last.end..insert_at]);
+    } else {
+        patched.push_str(&src[..insert_at]);
+    }
+    patched.push_str(prefix);
+    patched.push_str(&inserted);
+    patched.push_str(&src[resume_at..]);
+    patched
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn appends_missing_enum_members() {
+        let src = "export enum RoutesEnum {\n  Home = \"home\",\n}\n";
+        let (patched, updated) =
+            upsert_enum_members(src, "RoutesEnum", &[("Cliente", "clientes")]).unwrap();
+        assert_eq!(
+            patched,
+            "export enum RoutesEnum {\n  Home = \"home\",\n  Cliente = \"clientes\",\n}\n"
+        );
+        assert!(updated.is_empty());
+    }
+
+    #[test]
+    fn keeps_crlf_line_endings() {
+        let src = "export enum RoutesEnum {\r\n  Home = \"home\"\r\n}\r\n";
+        let (patched, _) =
+            upsert_enum_members(src, "RoutesEnum", &[("Cliente", "clientes")]).unwrap();
+        assert_eq!(
+            patched,
+            "export enum RoutesEnum {\r\n  Home = \"home\",\r\n  Cliente = \"clientes\",\r\n}\r\n"
+        );
+    }
+
+    #[test]
+    fn adds_the_comma_before_a_trailing_comment() {
+        let src = "export enum RoutesEnum {\n  Home = \"home\" // start page\n}\n";
+        let (patched, _) =
+            upsert_enum_members(src, "RoutesEnum", &[("Cliente", "clientes")]).unwrap();
+        assert_eq!(
+            patched,
+            "export enum RoutesEnum {\n  Home = \"home\", // start page\n  Cliente = \"clientes\",\n}\n"
+        );
+    }
+
+    #[test]
+    fn extends_a_one_line_enum() {
+        let src = "export enum RoutesEnum { Home = \"home\" }\n";
+        let (patched, _) =
+            upsert_enum_members(src, "RoutesEnum", &[("Cliente", "clientes")]).unwrap();
+        assert_eq!(
+            patched,
+            "export enum RoutesEnum { Home = \"home\",\n  Cliente = \"clientes\",\n}\n"
+        );
+    }
+
+    #[test]
+    fn updates_members_in_place() {
+        let src = "export enum RoutesEnum {\n  Cliente = \"old\", // kept\n  Home = \"home\",\n}\n";
+        let (patched, updated) =
+            upsert_enum_members(src, "RoutesEnum", &[("Cliente", "clientes")]).unwrap();
+        assert_eq!(
+            patched,
+            "export enum RoutesEnum {\n  Cliente = \"clientes\", // kept\n  Home = \"home\",\n}\n"
+        );
+        assert_eq!(updated, ["RoutesEnum.Cliente from \"old\" to \"clientes\""]);
+    }
+
+    #[test]
+    fn leaves_unchanged_template_literal_values() {
+        let src = "export enum RoutesEnum { A = `a`, // c\n}\n";
+        let (patched, updated) = upsert_enum_members(src, "RoutesEnum", &[("A", "a")]).unwrap();
+        assert_eq!(patched, src);
+        assert!(updated.is_empty());
+    }
+
+    #[test]
+    fn replaces_whole_template_literal_values() {
+        let src = "export enum RoutesEnum {\n  A = `a${b}c`,\n  B = `b`,\n}\n";
+        let (patched, updated) = upsert_enum_members(src, "RoutesEnum", &[("A", "x")]).unwrap();
+        assert_eq!(
+            patched,
+            "export enum RoutesEnum {\n  A = \"x\",\n  B = `b`,\n}\n"
+        );
+        assert_eq!(updated, ["RoutesEnum.A from `a${b}c` to \"x\""]);
+    }
+
+    #[test]
+    fn fills_an_empty_array() {
+        let src = "export const routes: Routes = [\n];\n";
+        let patched = insert_array_entry(src, "{ path: \"clientes\" },\n").unwrap();
+        assert_eq!(
+            patched,
+            "export const routes: Routes = [\n{ path: \"clientes\" },\n];\n"
+        );
+    }
+
+    #[test]
+    fn extends_a_one_line_array() {
+        let src = "export const navItems = [{ url: RoutesEnum.Home }];\n";
+        let patched = insert_array_entry(src, "{ url: RoutesEnum.Cliente },\n").unwrap();
+        assert_eq!(
+            patched,
+            "export const navItems = [{ url: RoutesEnum.Home },\n{ url: RoutesEnum.Cliente },\n];\n"
+        );
+        assert!(array_references(&patched, "RoutesEnum", "Cliente").unwrap());
+        assert!(!array_references(src, "RoutesEnum", "Cliente").unwrap());
+    }
+
+    #[test]
+    fn ignores_brackets_in_strings_and_comments() {
+        let src = "// ] not the end\nexport const items = [\n  \"]\",\n  `${\"]\"}`,\n];\n";
+        let patched = insert_array_entry(src, "\"x\",\n").unwrap();
+        assert_eq!(
+            patched,
+            "// ] not the end\nexport const items = [\n  \"]\",\n  `${\"]\"}`,\n\"x\",\n];\n"
+        );
+    }
+}
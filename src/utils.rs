@@ -1,3 +0,0 @@
-pub trait TitleCase {
-    fn title(&self) -> String;
-}
@@ -0,0 +1,315 @@
+use crate::models::{Field, Property};
+use crate::naming::{camel_case, identifier, pascal_case};
+
+/// Words TypeScript rejects as variable or parameter names.
+const RESERVED: &[&str] = &[
+    "break",
+    "case",
+    "catch",
+    "class",
+    "const",
+    "continue",
+    "debugger",
+    "default",
+    "delete",
+    "do",
+    "else",
+    "enum",
+    "export",
+    "extends",
+    "false",
+    "finally",
+    "for",
+    "function",
+    "if",
+    "implements",
+    "import",
+    "in",
+    "instanceof",
+    "interface",
+    "let",
+    "new",
+    "null",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "return",
+    "static",
+    "super",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typeof",
+    "var",
+    "void",
+    "while",
+    "with",
+    "yield",
+];
+
+/// Globals the generated files use, a model with one of these names would shadow them.
+const GLOBALS: &[&str] = &[
+    "Array",
+    "Boolean",
+    "Date",
+    "Error",
+    "Map",
+    "Number",
+    "Object",
+    "Observable",
+    "Promise",
+    "Set",
+    "String",
+    "Subject",
+    "Symbol",
+];
+
+/// Properties every model already declares.
+const MODEL_FIELDS: &[&str] = &["id", "created_at", "updated_at"];
+
+/// Section names in singular or plural: words made of letters and digits separated by
+/// spaces, `_` or `-`.
+pub fn validate_name(value: &str) -> Result<String, String> {
+    let value = value.trim();
+    check_characters(value, "spaces, _ or -", |c| matches!(c, ' ' | '_' | '-'))?;
+    check_identifier(value, &camel_case(value))?;
+    let model = pascal_case(value);
+    if GLOBALS.contains(&model.as_str()) {
+        return Err(format!(
+            "{} would name the model {}, which shadows the TypeScript global {}",
+            value, model, model
+        ));
+    }
+    Ok(value.to_owned())
+}
+
+/// Angular and API routes: segments of letters, digits, spaces, `_` or `-` separated by `/`.
+pub fn validate_route(value: &str) -> Result<String, String> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Err("The route can not be empty".to_owned());
+    }
+    if value.starts_with('/') || value.ends_with('/') {
+        return Err(format!("{} can not start or end with /", value));
+    }
+    if value.contains("//") {
+        return Err(format!("{} has an empty segment", value));
+    }
+    check_characters(value, "spaces, _, - or /", |c| {
+        matches!(c, ' ' | '_' | '-' | '/')
+    })?;
+    Ok(value.to_owned())
+}
+
+/// Property keys: letters, digits, spaces or `_`, turned into the identifier used in the model.
+pub fn validate_property(value: &str) -> Result<String, String> {
+    let value = value.trim();
+    check_characters(value, "spaces or _", |c| matches!(c, ' ' | '_'))?;
+    let key = identifier(value);
+    check_identifier(value, &key)?;
+    if MODEL_FIELDS.contains(&key.as_str()) {
+        return Err(format!("{} is already declared by every model", key));
+    }
+    Ok(key)
+}
+
+/// Comma separated values of an `Enum` property, each one becoming a member of a TypeScript
+/// string enum.
+pub fn validate_enum_values(value: &str) -> Result<Vec<String>, String> {
+    let values: Vec<String> = value
+        .split(',')
+        .map(|value| value.trim().to_owned())
+        .filter(|value| !value.is_empty())
+        .collect();
+    if values.is_empty() {
+        return Err("An Enum needs at least one value, separate them with commas".to_owned());
+    }
+    let mut members = Vec::new();
+    for value in &values {
+        if value.contains(['"', '\\', '`']) {
+            return Err(format!("{} can not contain quotes or backslashes", value));
+        }
+        let member = pascal_case(value);
+        match member.chars().next() {
+            None => return Err(format!("{} has no letters to name its enum member", value)),
+            Some(first) if first.is_ascii_digit() => {
+                return Err(format!("{} can not start with a digit", value))
+            }
+            Some(_) => {}
+        }
+        if members.contains(&member) {
+            return Err(format!("{} is repeated", value));
+        }
+        members.push(member);
+    }
+    Ok(values)
+}
+
+/// Default value of a property, written the way its kind is: a number, `true` or `false`, one
+/// of the Enum values, a `YYYY-MM-DD` date, the id of the related entity or any text.
+pub fn validate_default(kind: Property, values: &[String], value: &str) -> Result<String, String> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Err("The default can not be empty".to_owned());
+    }
+    match kind {
+        Property::Number => {
+            validate_limit(value)?;
+        }
+        Property::Boolean => {
+            return match value.to_lowercase().as_str() {
+                boolean @ ("true" | "false") => Ok(boolean.to_owned()),
+                _ => Err(format!("{} is not true or false", value)),
+            };
+        }
+        Property::Enum if !values.iter().any(|allowed| allowed == value) => {
+            return Err(format!(
+                "{} is not one of the values {}",
+                value,
+                values.join(", ")
+            ));
+        }
+        Property::Date => {
+            let is_date = value.len() == 10
+                && value.char_indices().all(|(index, c)| match index {
+                    4 | 7 => c == '-',
+                    _ => c.is_ascii_digit(),
+                });
+            if !is_date {
+                return Err(format!("{} is not a date, write it as YYYY-MM-DD", value));
+            }
+        }
+        Property::Related if !matches!(value.parse::<u64>(), Ok(id) if id > 0) => {
+            return Err(format!("{} is not the id of a related entity", value));
+        }
+        _ => {}
+    }
+    Ok(value.to_owned())
+}
+
+/// A minimum or maximum, any finite number.
+pub fn validate_limit(value: &str) -> Result<String, String> {
+    let value = value.trim();
+    match value.parse::<f64>() {
+        Ok(limit) if limit.is_finite() => Ok(value.to_owned()),
+        _ => Err(format!("{} is not a number", value)),
+    }
+}
+
+/// Checks that the modifiers of a property apply to its kind and agree with each other.
+pub fn validate_modifiers(field: &Field) -> Result<(), String> {
+    let limits = [field.min, field.max];
+    if !field.kind.has_limits() && limits.iter().any(Option::is_some) {
+        return Err(format!("{} properties do not take min or max", field.kind));
+    }
+    if field.kind.is_text()
+        && limits
+            .iter()
+            .flatten()
+            .any(|limit| *limit < 0.0 || limit.fract() != 0.0)
+    {
+        return Err("The min and max of a text are lengths, use whole numbers".to_owned());
+    }
+    if let (Some(min), Some(max)) = (field.min, field.max) {
+        if min > max {
+            return Err(format!("min {} is larger than max {}", min, max));
+        }
+    }
+    match &field.pattern {
+        Some(_) if !field.kind.has_pattern() => {
+            return Err(format!("{} properties do not take a pattern", field.kind));
+        }
+        Some(pattern) if pattern.is_empty() => {
+            return Err("The pattern can not be empty".to_owned());
+        }
+        _ => {}
+    }
+    if field.unique && !field.kind.can_be_unique() {
+        return Err(format!("{} properties can not be unique", field.kind));
+    }
+    if field.kind == Property::Related {
+        if let Some(target) = &field.target {
+            validate_name(target).map_err(|err| format!("target: {}", err))?;
+        } else if field.cardinality.is_many() {
+            return Err(format!(
+                "A {} relation needs the target model",
+                field.cardinality
+            ));
+        }
+        validate_property(&field.display).map_err(|err| format!("display: {}", err))?;
+        if field.cardinality.is_many() && field.default.is_some() {
+            return Err(format!(
+                "{} relations do not take a default",
+                field.cardinality
+            ));
+        }
+        if field.cardinality.is_many() && field.nullable {
+            return Err(format!(
+                "{} relations are an empty list, not null",
+                field.cardinality
+            ));
+        }
+    } else if field.target.is_some()
+        || field.cardinality.is_many()
+        || field.display != Field::new(field.kind).display
+    {
+        return Err(format!(
+            "{} properties do not take a target, cardinality or display",
+            field.kind
+        ));
+    }
+    if let Some(default) = &field.default {
+        let default = validate_default(field.kind, &field.values, default)
+            .map_err(|err| format!("default: {}", err))?;
+        if field.kind == Property::Number {
+            let number: f64 = default.parse().unwrap_or_default();
+            if field.min.is_some_and(|min| number < min)
+                || field.max.is_some_and(|max| number > max)
+            {
+                return Err(format!("default {} is outside of min and max", default));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn check_characters(
+    value: &str,
+    separators: &str,
+    is_separator: impl Fn(char) -> bool,
+) -> Result<(), String> {
+    if value.is_empty() {
+        return Err("The name can not be empty".to_owned());
+    }
+    match value
+        .chars()
+        .find(|c| !c.is_alphanumeric() && !is_separator(*c))
+    {
+        Some(c) => Err(format!(
+            "{} contains {:?}, use only letters, digits, {}",
+            value, c, separators
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Checks the identifier derived from `value`.
+fn check_identifier(value: &str, derived: &str) -> Result<(), String> {
+    match derived.chars().next() {
+        None => Err(format!(
+            "{} has no letters that can be used in an identifier",
+            value
+        )),
+        Some(first) if first.is_ascii_digit() => {
+            Err(format!("{} can not start with a digit", value))
+        }
+        Some(_) if RESERVED.contains(&derived) => Err(format!(
+            "{} is a reserved word in TypeScript, choose another name",
+            derived
+        )),
+        Some(_) => Ok(()),
+    }
+}
@@ -0,0 +1,29 @@
+import { Component } from "@angular/core";
+import { MatDialogRef, MAT_DIALOG_DATA } from "@angular/material/dialog";
+import { ActivatedRoute, Router } from "@angular/router";
+import { RoutesEnum } from "{{ page_routes_import }}";
+import { FormComponent } from "./form.component";
+
+@Component({
+  templateUrl: "form-page.component.html",
+  styleUrls: ["{{ page_app_import }}/app.component.css"],
+  providers: [
+    {
+      // "nuevo" has no {{ section }} to preload, the form creates one.
+      provide: MAT_DIALOG_DATA,
+      useFactory: (route: ActivatedRoute) => ({
+        entityForEdit: route.snapshot.data["{{ section }}"],
+      }),
+      deps: [ActivatedRoute],
+    },
+    {
+      // Closing the page goes back to the list instead of closing a dialog.
+      provide: MatDialogRef,
+      useFactory: (router: Router) => ({
+        close: () => router.navigateByUrl(`/${RoutesEnum.{{ model }}}/{{ list_path }}`),
+      }),
+      deps: [Router],
+    },
+  ],
+})
+export class FormPageComponent extends FormComponent {}
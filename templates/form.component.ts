@@ -0,0 +1,207 @@
+import { HttpErrorResponse } from "@angular/common/http";
+import { Component, Inject, OnInit } from "@angular/core";
+{% set empty_as_null = fields | selectattr("nullable") | selectattr("kind", "in", ["String", "Text", "Email", "Url", "Phone"]) | list %}
+{% set payload = "this.payload" if empty_as_null else "this.form.value" %}
+{% if fields | selectattr("unique") | list %}
+import { AbstractControl, AsyncValidatorFn, FormBuilder, ValidationErrors, Validators } from "@angular/forms";
+{% else %}
+import { Validators, FormBuilder } from "@angular/forms";
+{% endif %}
+import { MatDialogRef, MAT_DIALOG_DATA } from "@angular/material/dialog";
+import { MatSnackBar } from "@angular/material/snack-bar";
+{% if fields | selectattr("unique") | list %}
+import { map, Observable, of, switchMap, timer } from "rxjs";
+{% else %}
+import { Observable } from "rxjs";
+{% endif %}
+import { RoutesEnum } from "{{ page_routes_import }}";
+import { RootService } from "{{ page_root_service_import }}";
+import { {{ model }}{% for field in fields if field.kind == "Enum" %}, {{ field.enum_name }}{% endfor %} } from "{{ page_models_import }}/{{ section_file }}.model";
+{% for relation in relations if relation.model != model and relation.in_form %}
+import { {{ relation.model }} } from "{{ page_models_import }}/{{ relation.file }}.model";
+{% endfor %}
+{% for relation in relations if relation.service %}
+import { {{ relation.service.class }} } from "{{ relation.service.page_import }}";
+{% endfor %}
+
+@Component({
+  templateUrl: "form.component.html",
+  styleUrls: ["{{ page_app_import }}/app.component.css"],
+})
+export class FormComponent implements OnInit {
+  form = this.fb.group({
+{% for field in fields if field.in_form %}
+{% if field.unique %}
+    {{ field.control }}: [{{ field.initial }}, [{{ field.validators | join(", ") }}], [this.unique("{{ field.control }}")]],
+{% elif field.validators | length > 1 %}
+    {{ field.control }}: [{{ field.initial }}, [{{ field.validators | join(", ") }}]],
+{% elif field.validators %}
+    {{ field.control }}: [{{ field.initial }}, {{ field.validators[0] }}],
+{% else %}
+    {{ field.control }}: [{{ field.initial }}],
+{% endif %}
+{% endfor %}
+  });
+{% for field in fields if field.kind == "Related" and field.in_form %}
+{% if field.service %}
+  {{ field.related }}Options$: Observable<{{ field.related_model }}[]> = this.{{ field.service.var }}.{{ field.service.getter }};
+{% else %}
+  {{ field.related }}Options$: Observable<{{ field.related_model }}[]> = this.rootService.getAllActive<{{ field.related_model }}[]>(RoutesEnum.{{ field.related_model }}Api);
+{% endif %}
+{% endfor %}
+{% for field in fields if field.kind == "Enum" %}
+  {{ field.key }}Options = Object.values({{ field.enum_name }});
+{% endfor %}
+  {{ section }}?: {{ model }};
+
+  constructor(
+    @Inject (MAT_DIALOG_DATA)public data:any,
+    private rootService: RootService,
+{% for relation in relations if relation.service %}
+    private {{ relation.service.var }}: {{ relation.service.class }},
+{% endfor %}
+    private snackbar: MatSnackBar,
+    private fb: FormBuilder,
+    private dialog: MatDialogRef<FormComponent>
+  ) {
+    this.getEntity();
+  }
+
+  protected getEntity(): void {
+    if (this.data) {
+      const { entityForEdit } = this.data;
+      this.{{ section }} = entityForEdit;
+    }
+  }
+  ngOnInit(): void {
+    if (this.{{ section }}) {
+      this.form.reset({
+{% for field in fields if field.in_form %}
+        {{ field.control }}: this.{{ section }}.{{ field.control }},
+{% endfor %}
+      });
+{% for field in fields if field.readonly and field.in_form %}
+      this.form.get("{{ field.control }}")?.disable();
+{% endfor %}
+    }
+  }
+
+{% if empty_as_null %}
+  /** Form value to send, with the empty nullable texts as `null`. */
+  protected get payload() {
+    const value = this.form.value;
+    return {
+      ...value,
+{% for field in empty_as_null %}
+      {{ field.control }}: value.{{ field.control }} === "" ? null : value.{{ field.control }},
+{% endfor %}
+    };
+  }
+
+{% endif %}
+{% if fields | selectattr("unique") | list %}
+  /**
+   * Fails when another {{ section_label }} already has the value of the control. Angular drops the
+   * pending check on every change, so the list is only requested once typing pauses.
+   */
+  protected unique(key: keyof {{ model }}): AsyncValidatorFn {
+    return (control: AbstractControl): Observable<ValidationErrors | null> =>
+      control.value === null || control.value === ""
+        ? of(null)
+        : timer(500).pipe(
+            switchMap(() => this.rootService.getAll(RoutesEnum.{{ model }}Api)),
+            map((items) =>
+              (items as {{ model }}[]).some(
+                (item) => item[key] === control.value && item.id !== this.{{ section }}?.id
+              )
+                ? { unique: true }
+                : null
+            )
+          );
+  }
+
+{% endif %}
+  close(): void {
+    this.dialog.close();
+  }
+
+  submit(): void {
+    // console.log(this.form.value);
+    if (this.{{ section }}) {
+      this.edit();
+    } else {
+      this.create();
+    }
+  }
+  create() {
+    this.rootService
+      .create(RoutesEnum.{{ model }}Api, {{ payload }})
+      .subscribe({
+        next: (resp) => {
+          this.snackbar.open(`{{ model }} creado correctamente.`, "Ok", {
+            duration: 2500,
+            panelClass: ["primary-snackbar"],
+            verticalPosition: "top",
+            horizontalPosition: "end",
+          });
+          this.dialog.close({ listChange: true });
+        },
+        error: (err: HttpErrorResponse) => {
+          this.snackbar.open(
+            "No pudimos crear el {{ model }}. " + `(${err.status})`,
+            "Ok",
+            {
+              panelClass: ["error-snackbar"],
+              verticalPosition: "top",
+              horizontalPosition: "end",
+            }
+          );
+          this.dialog.close({ listChange: false });
+        },
+      });
+  }
+  edit(): void {
+    if (this.{{ section }}) {
+      this.rootService
+        .update(
+          RoutesEnum.{{ model }}Api,
+          { ...{{ payload }}, id: this.{{ section }}.id },
+          this.{{ section }}.id
+        )
+        .subscribe({
+          next: (resp) => {
+            this.snackbar.open(
+              `${this.form.get("name")?.value} editado correctamente.`,
+              "Ok",
+              {
+                duration: 2500,
+                panelClass: ["primary-snackbar"],
+                verticalPosition: "top",
+                horizontalPosition: "end",
+              }
+            );
+            this.dialog.close({ listChange: true });
+          },
+          error: (err: HttpErrorResponse) => {
+            this.snackbar.open(
+              "No se completó el proceso de editado. " + `(${err.status})`,
+              "Ok",
+              {
+                panelClass: ["error-snackbar"],
+                verticalPosition: "top",
+                horizontalPosition: "end",
+              }
+            );
+            this.dialog.close({ listChange: false });
+          },
+        });
+    }
+  }
+
+  get title(): string {
+    return this.{{ section }}
+      ? `Editando ${this.{{ section }}.name}`
+      : "Creando nuevo {{ section_label }}";
+  }
+}
+
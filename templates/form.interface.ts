@@ -0,0 +1,5 @@
+import { FormInterface } from "../../../root/root-form/form/form.component";
+
+export interface {{ model }}Form extends FormInterface{
+}
+
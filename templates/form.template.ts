@@ -0,0 +1,28 @@
+import { Validators } from "@angular/forms";
+import {
+  InputTemplate,
+  InputTypes,
+} from "../../../shared/models/basic/template.form.model";
+import { InitialState } from "../../../root/root-form/inputs/inputs.component";
+/*
+{% for field in fields %}
+{{ field.key }}: {{ field.kind }}
+{% endfor %}
+*/
+
+export const initialState: { [x: string]: InitialState } = {
+  name: { value: null, disabled: false , validators: [Validators.required] },
+};
+
+
+export const campsTemplate: Array<InputTemplate[]> = [
+  [
+    {
+      name: "name",
+      class: "col-sm-6",
+      label: "Nombre Visible",
+      required: true,
+      typeinput: "text",
+    },
+  ]
+];
@@ -0,0 +1,93 @@
+import { ComponentType } from "@angular/cdk/portal";
+import { Component, OnInit } from "@angular/core";
+import { MatDialog } from "@angular/material/dialog";
+import { ActivatedRoute, Router } from "@angular/router";
+import { delay, filter, Observable, switchMap } from "rxjs";
+import { InteractionsId } from "{{ page_constants_import }}/interactions";
+import { RoutesEnum } from "{{ page_routes_import }}";
+import { UserRole } from "{{ page_constants_import }}/userRoles";
+import { ListBaseComponent } from "{{ page_app_import }}/root/root-list/list-base/list-base.component";
+import { {{ model }}Service } from "{{ page_service_import }}";
+import { RootService } from "{{ page_root_service_import }}";
+import { InteractionService, InteractionSource } from "{{ page_app_import }}/services/interaction/interaction.service";
+import { LoginService } from "{{ page_app_import }}/services/login/login.service";
+import { ModalService } from "{{ page_app_import }}/services/modals/modals.service";
+import { ArrayResponse } from "{{ page_models_import }}/basic/res.array.model";
+import { FormComponent } from "../form/form.component";
+import { {{ model }} } from "{{ page_models_import }}/{{ section_file }}.model";
+
+@Component({
+  selector: "app-list",
+  templateUrl: "./list.component.html",
+  styleUrls: ["{{ page_app_import }}/app.component.css"],
+})
+export class ListComponent extends ListBaseComponent implements OnInit {
+  constructor(
+    private logService: LoginService,
+    activatedRoute: ActivatedRoute,
+    rootService: RootService,
+    router: Router,
+    modalService: ModalService,
+    interaction: InteractionService,
+    dialog: MatDialog,
+    private {{ section }}Service: {{ model }}Service
+  ) {
+    super(activatedRoute, router, rootService, modalService, interaction, dialog);
+  }
+  userRole?: UserRole;
+  userId?: string|null;
+  {{ plural }}List$?: Observable<{{ model }}[]>;
+  formComponentEdit: ComponentType<FormComponent> = FormComponent;
+  routeEnum = RoutesEnum.{{ model }}Api;
+
+  ngOnInit() {
+    this.{{ plural }}List$ = this.{{ section }}Service.{{ plural }};
+    this.subscriptions.add(
+      this.activatedRoute.queryParams.subscribe((query)=>{
+        const {page, take, search} = query;
+        this.queryParams.queryParams={page, take, search};
+      })
+    );
+    // The routing module resolves the page again whenever the query params change.
+    this.subscriptions.add(
+      this.activatedRoute.data.subscribe((data)=>{
+        const res: ArrayResponse<{{ model }}> = data["{{ plural }}"];
+        this.{{ section }}Service.set{{ plural_titled }}(res.data);
+        this.setPage(res);
+      })
+    );
+    this.subscriptions.add(
+      this.interactionService.interaction
+        .pipe(
+          filter(
+            (e) =>
+              (e.source === InteractionSource.button &&
+              e.id === InteractionsId.listChange) || (e.id === InteractionsId.RootForm)
+          ),
+          delay(800)
+        )
+        .subscribe((res) => this.listChange())
+    );
+  }
+
+  listChange(e?: any): void {
+    this.rootService
+      .index<{{ model }}>(this.apiSection ?? '')
+      .pipe(switchMap(async (res) => this.{{ section }}Service.set{{ plural_titled }}(res.data)))
+      .subscribe();
+    this.dialog.closeAll();
+  }
+  
+  disableCard(e: any): void {
+    if (e.status === 0) {
+      document
+        .getElementById("card" + e.i)
+        ?.classList.replace("fade-in", "fade-out-left");
+    } else {
+      document
+        .getElementById("card" + e.i)
+        ?.classList.replace("fade-out-left", "fade-in");
+    }
+  }
+}
+
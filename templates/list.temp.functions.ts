@@ -0,0 +1,46 @@
+import { {{ model }} } from "../../../shared/models/{{ section_file }}.model";
+import {
+  MainListTemplate,
+  ListTemplate,
+  SpecialBlock,
+  ButtonAction,
+  Color,
+  ButtonType,
+} from "../../../shared/models/basic/template.list.model";
+import { FontDataRender } from "../../../shared/models/basic/fonts.template.const";
+
+export const template: MainListTemplate = {
+  headIcon: "fas {{ icon }} fa-2x ms-1 mt-1",
+};
+
+export function setData(obj: {{ model }}): ListTemplate {
+  const  btnDestacado: ButtonAction = {
+    name: obj.id ? "Destacado" : "No destacado",
+    icon: obj.id ? "fas fa-star" : '',
+    color: obj.id ? Color.warning : Color.medium,
+    type: ButtonType.chip,
+    tooltip: obj.id ? "No destacar" : "Destacar",
+    values: { name: "isDestacado", status: obj.id, id: obj.id },
+  };
+  return {
+    nombreForDelete: `${obj.id}`,
+    avatar: "{{ icon }}",
+    alerted:{
+      message: 'Cod. '+obj.id,
+      color: Color.primary
+    },
+    header: { header: "${obj.id}", fontEnum: FontDataRender.Header },
+    personalEdit: true,
+    personalDelete: true,
+    id: obj.id,
+    specialButtons: [btnDestacado],
+    status: obj.id?`${obj.id ? "true" : "false"}`: undefined,
+    properties: [],
+  };
+}
+export function showSetData(obj: {{ model }}): ListTemplate{
+    return {  } as ListTemplate
+}
+export function setValueQueryPlus(obj: {{ model }}) {
+  return {  };
+}
@@ -0,0 +1,56 @@
+import { Component, Inject, OnInit, AfterViewInit } from "@angular/core";
+import { ChangeDetectorRef } from "@angular/core";
+import { LoginService } from "{{ page_app_import }}/services/login/login.service";
+import { MatDialog, MatDialogRef, MAT_DIALOG_DATA } from "@angular/material/dialog";
+import { ModalFormBaseComponent } from "{{ page_app_import }}/root/root-form/modal-form-base/modal-form-base.component";
+import { DataEditForm } from "{{ page_models_import }}/basic/data.routes.model";
+import { RootService } from "{{ page_root_service_import }}";
+import { InteractionService } from "{{ page_app_import }}/services/interaction/interaction.service";
+import { {{ model }} } from "{{ page_models_import }}/{{ section_file }}.model";
+import { {{ model }}Form } from "../../templates/form.interface";
+
+@Component({
+  templateUrl: "modal.component.html",
+  styleUrls: ["{{ page_app_import }}/app.component.css"],
+})
+export class ModalComponent extends ModalFormBaseComponent implements {{ model }}Form, OnInit, AfterViewInit {
+  constructor(
+    private cdr: ChangeDetectorRef,
+    dialogRef: MatDialogRef<ModalComponent>,
+    logService: LoginService,
+    interaction: InteractionService,
+    dialog: MatDialog,
+    rootService: RootService,
+    @Inject(MAT_DIALOG_DATA) data: DataEditForm
+  ) {
+    super(dialogRef, logService, data, interaction, dialog, rootService);
+  }
+  override activoInactivoOptions: any[] = [
+{value: "destacado", description: "Destacado" },
+{value: "estado", description: "Activo" },
+  ];
+  
+{{ section }}ForEdit?: {{ model }};
+
+  ngOnInit() {
+    this.setStartingValues();
+  }
+
+  setStartingValues(){
+    // const { {{ section }} } = this.data.resolvers!;
+    this.optionsPassed["opciones"] = this.activoInactivoOptions; // Cada Campo usa su name como respectiva key
+    this.{{ section }}ForEdit = this.data.entityForEdit;
+    if (this.{{ section }}ForEdit) {
+    // this.{{ section }}ForEdit.opciones = [];
+    // if (this.{{ section }}ForEdit?.estado){
+    //   this.{{ section }}ForEdit.opciones.push("estado");
+    // }
+    }
+  }
+  
+  ngAfterViewInit(): void {
+    //ACA HACER USO DEL FORM
+    this.inputsControls = this.form?.inputs.toArray();
+  }
+}
+
@@ -0,0 +1,43 @@
+{% for relation in relations if relation.model != model %}
+import { {{ relation.model }} } from "./{{ relation.file }}.model";
+{% if loop.last %}
+
+{% endif %}
+{% endfor %}
+{% for field in fields if field.kind == "Enum" %}
+export enum {{ field.enum_name }} {
+{% for option in field.options %}
+  {{ option.member }} = "{{ option.value }}",
+{% endfor %}
+}
+
+{% endfor %}
+export interface {{ model }} {
+  id : number;
+{% for field in fields %}
+{% set optional = "" if field.required else "?" %}
+{% set null = " | null" if field.nullable else "" %}
+{% if field.kind in ["String", "Text", "Email", "Url", "Phone"] %}
+  {{ field.key }}{{ optional }} : string{{ null }};
+{% elif field.kind == "Boolean" %}
+  {{ field.key }}{{ optional }} : boolean{{ null }};
+{% elif field.kind == "Enum" %}
+  {{ field.key }}{{ optional }} : {{ field.enum_name }}{{ null }};
+{% elif field.kind == "Number" %}
+  {{ field.key }}{{ optional }} : number{{ null }};
+{% elif field.kind == "Date" %}
+  {{ field.key }}{{ optional }} : Date{{ null }};
+{% elif field.kind == "Related" and field.cardinality == "HasMany" %}
+  {{ field.related }}? : {{ field.related_model }}[];
+{% elif field.kind == "Related" and field.many %}
+  {{ field.control }}{{ optional }} : number[];
+  {{ field.related }}? : {{ field.related_model }}[];
+{% elif field.kind == "Related" %}
+  {{ field.control }}{{ optional }} : number{{ null }};
+  {{ field.related }}? : {{ field.related_model }}{{ null }};
+{% endif %}
+{% endfor %}
+  created_at : Date;
+  updated_at: Date;
+}
+
@@ -0,0 +1,48 @@
+import { CommonModule } from '@angular/common';
+import { NgModule } from '@angular/core';
+import { ReactiveFormsModule, FormsModule } from '@angular/forms';
+import { PaginationModule } from 'ngx-bootstrap/pagination';
+import { SharedModule } from '{{ module_shared_import }}/shared.module';
+import { {{ plural_titled }}RoutingModule, routingComponents } from './{{ api_route }}-routing.module';
+import { RootModule } from '{{ module_app_import }}/root/root.module';
+import { MatPaginatorModule } from '@angular/material/paginator';
+import { HeaderListadoModule } from '{{ module_shared_import }}/components/header-listado/header-listado.module';
+import { MatDialogModule } from '@angular/material/dialog';
+import { MatFormFieldModule } from '@angular/material/form-field';
+import { MatInputModule } from '@angular/material/input';
+import { MatButtonModule } from '@angular/material/button';
+import { MatDatepickerModule } from '@angular/material/datepicker';
+import { MatNativeDateModule } from '@angular/material/core';
+import { MatSelectModule } from '@angular/material/select';
+import { MatSlideToggleModule } from '@angular/material/slide-toggle';
+import { StatusModule } from '{{ module_shared_import }}/components/buttons/status/status.module';
+import { EditModule } from '{{ module_shared_import }}/components/buttons/edit/edit.module';
+import { DeleteModule } from '{{ module_shared_import }}/components/buttons/delete/delete.module';
+
+@NgModule({
+  imports: [
+    {{ plural_titled }}RoutingModule,
+    ReactiveFormsModule,
+    CommonModule,
+    PaginationModule,
+    FormsModule,
+    SharedModule,
+    RootModule,
+    MatPaginatorModule,
+    MatDialogModule,
+    HeaderListadoModule,
+    MatFormFieldModule,
+    MatInputModule,
+    MatButtonModule,
+    MatDatepickerModule,
+    MatNativeDateModule,
+    MatSelectModule,
+    MatSlideToggleModule,
+    StatusModule,
+    EditModule,
+    DeleteModule
+  ],
+  declarations: routingComponents
+})
+export class {{ plural_titled }}Module { }
+
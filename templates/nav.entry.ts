@@ -0,0 +1,14 @@
+  {
+    title: true,
+    name: RoutesEnum.{{ model }},
+    class: "strong-font",
+    children: [
+      {
+        name: RoutesEnum.{{ model }},
+        url: `${RoutesEnum.{{ model }}}{{ nav_url_suffix }}`,
+        icon: "fa {{ icon }} scaling",
+        class: "strong-font",
+        active: RoutesEnum.{{ model }},
+      },
+    ],
+  },
@@ -0,0 +1,54 @@
+import { Injectable } from '@angular/core';
+import {Resolve,
+  RouterStateSnapshot,
+  ActivatedRouteSnapshot
+} from '@angular/router';
+import { Observable } from 'rxjs';
+import { RoutesEnum } from '{{ service_routes_import }}';
+import { RootService } from '{{ service_root_service_import }}';
+{% for relation in relations if relation.service %}
+import { {{ relation.model }} } from '{{ service_models_import }}/{{ relation.file }}.model';
+import { {{ relation.service.class }} } from '{{ relation.service.resolver_import }}';
+{% endfor %}
+
+@Injectable({
+  providedIn: 'root'
+})
+export class All{{ plural_titled }}Resolver implements Resolve<unknown> {
+  constructor(private rootService:RootService){}
+  resolve(route: ActivatedRouteSnapshot, state: RouterStateSnapshot): Observable<unknown> {
+    return this.rootService.getAll(RoutesEnum.{{ model }}Api)
+  }
+}
+@Injectable({
+  providedIn: 'root'
+})
+export class {{ model }}Resolver implements Resolve<unknown> {
+  constructor(private rootService:RootService){}
+  resolve(route: ActivatedRouteSnapshot, state: RouterStateSnapshot): Observable<unknown> {
+    const{id} = route.params
+    return this.rootService.getOne(RoutesEnum.{{ model }}Api,id)
+  }
+}
+@Injectable({
+  providedIn: 'root'
+})
+export class {{ plural_titled }}Resolver implements Resolve<unknown> {
+  constructor(private rootService:RootService){}
+  resolve(route: ActivatedRouteSnapshot, state: RouterStateSnapshot): Observable<unknown> {
+    const {take,page,search} = route.queryParams
+    return this.rootService.index(RoutesEnum.{{ model }}Api,page,take,search)
+  }
+}
+{% for relation in relations if relation.service %}
+@Injectable({
+  providedIn: 'root'
+})
+export class {{ relation.service.resolver }} implements Resolve<{{ relation.model }}[]> {
+  constructor(private {{ relation.service.var }}:{{ relation.service.class }}){}
+  resolve(route: ActivatedRouteSnapshot, state: RouterStateSnapshot): Observable<{{ relation.model }}[]> {
+    return this.{{ relation.service.var }}.{{ relation.service.nurture }}()
+  }
+}
+{% endfor %}
+
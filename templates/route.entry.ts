@@ -0,0 +1,7 @@
+  {
+    path: RoutesEnum.{{ model }},
+    loadChildren: () =>
+      import("{{ views_import }}/{{ api_route }}/{{ api_route }}.module").then(
+        (m) => m.{{ plural_titled }}Module
+      ),
+  },
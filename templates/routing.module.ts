@@ -0,0 +1,92 @@
+import { NgModule } from "@angular/core";
+import { Routes, RouterModule } from "@angular/router";
+import { RoutesEnum } from "{{ module_routes_import }}";
+import { FormComponent } from "./pages/form/form.component";
+import { ListComponent } from "./pages/list/list.component";
+import { ShowComponent } from "./pages/show/show.component";
+{% if pages %}
+import { FormPageComponent } from "./pages/form/form-page.component";
+import { ShowPageComponent } from "./pages/show/show-page.component";
+{% endif %}
+import {
+{% if pages %}
+  {{ model }}Resolver,
+{% endif %}
+  {{ plural_titled }}Resolver,
+{% for relation in relations if relation.service %}
+  {{ relation.service.resolver }},
+{% endfor %}
+} from "{{ resolvers_import }}";
+
+const section = RoutesEnum.{{ model }};
+const apiSection = RoutesEnum.{{ model }}Api;
+const data = {
+  section,
+  apiSection,
+  modalComponent: FormComponent,
+  showComponent: ShowComponent,
+};
+
+const routes: Routes = [
+  {
+    path: "",
+    data: {
+      title: "{{ plural_titled }}",
+    },
+{% if relations | selectattr("service") | list %}
+    // Preloads the options of the relations once for every route of the module.
+    resolve: {
+{% for relation in relations if relation.service %}
+      {{ relation.model | camel_case }}Options: {{ relation.service.resolver }},
+{% endfor %}
+    },
+{% endif %}
+    children: [
+      {
+        path: "{{ list_path }}",
+        component: ListComponent,
+        data,
+        // Runs again on every page change and search, so it only loads the requested page.
+        resolve: { {{ plural }}: {{ plural_titled }}Resolver },
+        runGuardsAndResolvers: "paramsOrQueryParamsChange",
+      },
+{% if pages %}
+      {
+        path: "nuevo",
+        component: FormPageComponent,
+        data,
+      },
+      {
+        path: ":id",
+        component: ShowPageComponent,
+        data,
+        resolve: { {{ section }}: {{ model }}Resolver },
+      },
+      {
+        path: ":id/editar",
+        component: FormPageComponent,
+        data,
+        resolve: { {{ section }}: {{ model }}Resolver },
+      },
+{% endif %}
+    ],
+  },
+];
+
+@NgModule({
+  imports: [RouterModule.forChild(routes)],
+  exports: [RouterModule],
+})
+export class {{ plural_titled }}RoutingModule {}
+{% if pages %}
+export const routingComponents = [
+  FormComponent,
+  ListComponent,
+  ShowComponent,
+  FormPageComponent,
+  ShowPageComponent,
+];
+{% else %}
+export const routingComponents = [FormComponent, ListComponent, ShowComponent];
+{% endif %}
+
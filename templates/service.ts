@@ -0,0 +1,43 @@
+import { Injectable } from "@angular/core";
+import { BehaviorSubject, Observable, Subject, tap } from "rxjs";
+import { RoutesEnum } from "{{ service_routes_import }}";
+import { {{ model }} } from "{{ service_models_import }}/{{ section_file }}.model";
+import { RootService } from "{{ service_root_service_import }}";
+
+@Injectable({
+  providedIn: "root",
+})
+export class {{ model }}Service {
+  #{{ section }}: Subject<{{ model }}> = new Subject<{{ model }}>();
+  #{{ plural }}: BehaviorSubject<{{ model }}[]> = new BehaviorSubject<{{ model }}[]>([]);
+
+  constructor(private rootService: RootService) {
+    this.#{{ section }}.subscribe((col) =>
+      this.#{{ plural }}.next([...this.#{{ plural }}.value, col])
+    );
+  }
+
+  get {{ section }} 
+  (): Observable<{{ model }}> {
+    return this.#{{ section }}.asObservable();
+  }
+
+  get {{ plural }}(): Observable<{{ model }}[]> {
+    return this.#{{ plural }}.asObservable();
+  }
+
+  set{{ plural_titled }}({{ plural }}: {{ model }}[]): void {
+    this.#{{ plural }}.next({{ plural }});
+  }
+
+  public nurture{{ plural_titled }}(): Observable<{{ model }}[]> {
+    return this.rootService
+      .getAllActive<{{ model }}[]>(RoutesEnum.{{ model }}Api)
+      .pipe(tap(({{ plural }}) => this.set{{ plural_titled }}({{ plural }})));
+  }
+
+  emit{{ model }}({{ section }}: {{ model }}): void {
+    this.#{{ section }}.next({{ section }});
+  }
+}
+
@@ -0,0 +1,15 @@
+import { Component } from "@angular/core";
+import { ActivatedRoute } from "@angular/router";
+import { RoutesEnum } from "{{ page_routes_import }}";
+import { {{ model }} } from "{{ page_models_import }}/{{ section_file }}.model";
+
+@Component({
+  templateUrl: "./show-page.component.html",
+  styleUrls: ["{{ page_app_import }}/app.component.css"],
+})
+export class ShowPageComponent {
+  {{ section }}?: {{ model }} = this.activatedRoute.snapshot.data["{{ section }}"];
+  listUrl = `/${RoutesEnum.{{ model }}}/{{ list_path }}`;
+
+  constructor(private activatedRoute: ActivatedRoute) {}
+}
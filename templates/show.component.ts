@@ -0,0 +1,17 @@
+import { Component, Inject } from "@angular/core";
+import { MAT_DIALOG_DATA } from "@angular/material/dialog";
+import { ModalShowBaseComponent } from "{{ page_app_import }}/root/root-show/modal-show-base/modal-show-base.component";
+import { DataModalShow } from "{{ page_models_import }}/basic/data.routes.model";
+import { {{ model }} } from "{{ page_models_import }}/{{ section_file }}.model";
+
+@Component({
+    templateUrl: "./show.component.html",
+  })
+  export class ShowComponent extends ModalShowBaseComponent {
+    constructor(
+    @Inject(MAT_DIALOG_DATA) data: DataModalShow<{{ model }}>
+    ){
+        super(data)
+    }
+  }
+